@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .chars()
+            .map(|ch| match ch {
+                '(' => 1,
+                ')' => -1,
+                _ => 0,
+            })
+            .collect())
+    }
+
+    fn part1(floors: &Self::Input) -> Result<impl Display> {
+        Ok(floors.iter().sum::<isize>())
+    }
+
+    fn part2(floors: &Self::Input) -> Result<impl Display> {
+        let Some((basement_index, _)) = floors
+            .iter()
+            .scan(0, |acc, next| {
+                *acc += next;
+
+                Some(*acc)
+            })
+            .enumerate()
+            .find(|(_, floor)| *floor < 0)
+        else {
+            anyhow::bail!("basement never reached");
+        };
+
+        Ok(basement_index + 1)
+    }
+}
+
+aoc_utils::example_tests!(Day01);
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use aoc_utils::Solution;
+
+fn parse_boxes(input: &str) -> Result<Vec<[usize; 3]>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut nums = line.split('x').map(|v| v.parse::<usize>());
+
+            let [Some(Ok(w)), Some(Ok(h)), Some(Ok(l)), None] =
+                std::array::from_fn(|_| nums.next())
+            else {
+                bail!("invalid line format")
+            };
+
+            let mut sides = [w, h, l];
+            sides.sort();
+
+            Ok(sides)
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<[usize; 3]>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_boxes(input)
+    }
+
+    fn part1(boxes: &Self::Input) -> Result<impl Display> {
+        Ok(boxes
+            .iter()
+            .map(|&[w, h, l]| {
+                let sides = [w * h, h * l, l * w];
+
+                sides.iter().map(|s| 2 * s).sum::<usize>() + sides.iter().min().unwrap()
+            })
+            .sum::<usize>())
+    }
+
+    fn part2(boxes: &Self::Input) -> Result<impl Display> {
+        Ok(boxes
+            .iter()
+            .map(|&[w, h, l]| 2 * (w + h) + w * h * l)
+            .sum::<usize>())
+    }
+}
+
+aoc_utils::example_tests!(Day02);
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::{Dir4, IVec2, Solution, SparseGrid};
+
+fn parse_deltas(input: &str) -> Result<Vec<IVec2>> {
+    input
+        .trim()
+        .chars()
+        .map(|ch| Ok(Dir4::try_from(ch)?.vec()))
+        .collect()
+}
+
+// Presents delivered to every house, with the santas taking turns to follow the deltas
+fn deliver(deltas: &[IVec2], santas: usize) -> SparseGrid<u32> {
+    let mut positions = vec![IVec2::ZERO; santas];
+    let mut houses = SparseGrid::new();
+
+    houses.insert(IVec2::ZERO, santas as u32);
+
+    for (i, delta) in deltas.iter().enumerate() {
+        let pos = &mut positions[i % santas];
+
+        *pos += *delta;
+        *houses.get_or_insert_default(*pos) += 1;
+    }
+
+    houses
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = Vec<IVec2>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_deltas(input)
+    }
+
+    fn part1(deltas: &Self::Input) -> Result<impl Display> {
+        Ok(deliver(deltas, 1).len())
+    }
+
+    fn part2(deltas: &Self::Input) -> Result<impl Display> {
+        Ok(deliver(deltas, 2).len())
+    }
+}
+
+aoc_utils::example_tests!(Day03);
@@ -0,0 +1,41 @@
+use std::{fmt::Display, io::Write};
+
+use anyhow::Result;
+use aoc_utils::Solution;
+use md5::{Digest, Md5};
+
+fn find_hash_suffix(key: &str, is_valid: impl Fn(&[u8]) -> bool) -> Result<usize> {
+    let mut hasher = Md5::new();
+
+    for i in 0.. {
+        write!(hasher, "{key}{i}")?;
+        let result = Digest::finalize_reset(&mut hasher);
+
+        if is_valid(&result) {
+            return Ok(i);
+        }
+    }
+
+    unreachable!()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(key: &Self::Input) -> Result<impl Display> {
+        find_hash_suffix(key, |hash| hash[0] == 0 && hash[1] == 0 && hash[2] < 16)
+    }
+
+    fn part2(key: &Self::Input) -> Result<impl Display> {
+        find_hash_suffix(key, |hash| hash[0] == 0 && hash[1] == 0 && hash[2] == 0)
+    }
+}
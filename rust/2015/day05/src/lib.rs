@@ -0,0 +1,51 @@
+use core::str;
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+fn is_nice(line: &str) -> bool {
+    line.chars()
+        .filter(|c| "aeiou".contains(*c))
+        .take(3)
+        .count()
+        == 3
+        && line.as_bytes().windows(2).any(|w| w[0] == w[1])
+        && !line
+            .as_bytes()
+            .windows(2)
+            .any(|w| matches!(&[w[0], w[1]], b"ab" | b"cd" | b"pq" | b"xy"))
+}
+
+fn is_nicer(line: &str) -> bool {
+    line.as_bytes()
+        .windows(2)
+        .enumerate()
+        .any(|(i, w)| line[(i + 2)..].contains(str::from_utf8(w).unwrap()))
+        && line.as_bytes().windows(3).any(|w| w[0] == w[2])
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    fn part1(words: &Self::Input) -> Result<impl Display> {
+        Ok(words.iter().filter(|line| is_nice(line)).count())
+    }
+
+    fn part2(words: &Self::Input) -> Result<impl Display> {
+        Ok(words.iter().filter(|line| is_nicer(line)).count())
+    }
+}
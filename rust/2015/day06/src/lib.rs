@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use aoc_utils::{I64Vec2, Rect, RectGrid, Solution};
+use winnow::{
+    ascii::digit1,
+    combinator::{empty, separated_pair},
+    error::InputError,
+    Parser,
+};
+
+type Point = (usize, usize);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Op {
+    On,
+    Off,
+    Toggle,
+}
+
+impl Op {
+    pub fn apply(self, val: bool) -> bool {
+        match self {
+            Op::On => true,
+            Op::Off => false,
+            Op::Toggle => !val,
+        }
+    }
+
+    pub fn apply_alt(self, val: usize) -> usize {
+        match self {
+            Op::On => val + 1,
+            Op::Toggle => val + 2,
+            Op::Off => val.saturating_sub(1),
+        }
+    }
+}
+
+fn rect(from: Point, to: Point) -> Rect {
+    Rect::new(
+        I64Vec2::new(from.0 as i64, from.1 as i64),
+        I64Vec2::new(to.0 as i64, to.1 as i64),
+    )
+}
+
+// Runs the instructions over a grid that starts out with every light at its default
+fn run<T: Clone + Default>(
+    instructions: &[(Op, Point, Point)],
+    mut f: impl FnMut(Op, &mut T),
+) -> RectGrid<T> {
+    let mut grid = RectGrid::new(instructions.iter().map(|&(_, p1, p2)| rect(p1, p2)));
+
+    for &(op, p1, p2) in instructions {
+        grid.apply(rect(p1, p2), |v| f(op, v));
+    }
+
+    grid
+}
+
+fn parse_point<'a>() -> impl Parser<&'a str, (usize, usize), InputError<&'a str>> {
+    separated_pair(digit1.parse_to(), ',', digit1.parse_to())
+}
+
+fn parse_line(mut input: &str) -> Result<(Op, Point, Point)> {
+    let Ok(((_, op), p1, _, p2)) = (
+        winnow::combinator::alt((
+            ("toggle ", empty.map(|_| Op::Toggle)),
+            ("turn on ", empty.map(|_| Op::On)),
+            ("turn off ", empty.map(|_| Op::Off)),
+        )),
+        parse_point(),
+        " through ",
+        parse_point(),
+    )
+        .parse_next(&mut input)
+    else {
+        bail!("failed to parse")
+    };
+
+    Ok((op, p1, p2))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input = Vec<(Op, Point, Point)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_line)
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display> {
+        let grid = run(instructions, |op, v: &mut bool| *v = op.apply(*v));
+
+        Ok(grid.sum_by(|&on| on as u64))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display> {
+        let grid = run(instructions, |op, v: &mut usize| *v = op.apply_alt(*v));
+
+        Ok(grid.sum_by(|&brightness| brightness as u64))
+    }
+}
+
+aoc_utils::example_tests!(Day06);
@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+fn code_minus_bytes(lines: &[String]) -> usize {
+    let mut code_minus_bytes = 0;
+
+    for line in lines {
+        code_minus_bytes += line.len();
+
+        let mut chars = line.chars().peekable();
+
+        // Skip leading "
+        chars.next();
+
+        while let Some(ch) = chars.next() {
+            match (ch, chars.peek()) {
+                ('"', None) => continue,
+                ('\\', Some('\\')) | ('\\', Some('"')) => {
+                    chars.next();
+                }
+                ('\\', Some('x')) => {
+                    let next = chars.clone().take(3).collect::<Vec<_>>();
+
+                    if next.len() == 3 && next[1].is_ascii_hexdigit() && next[2].is_ascii_hexdigit()
+                    {
+                        for _ in 0..3 {
+                            chars.next();
+                        }
+                    }
+                }
+                _ => (),
+            }
+
+            code_minus_bytes -= 1;
+        }
+    }
+
+    code_minus_bytes
+}
+
+fn code_minus_code(lines: &[String]) -> usize {
+    let mut code_minus_code = 0;
+
+    for line in lines {
+        code_minus_code += 2 + line
+            .chars()
+            .map(|c| match c {
+                '\\' | '"' => 2,
+                _ => 1,
+            })
+            .sum::<usize>()
+            - line.chars().count();
+    }
+
+    code_minus_code
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<impl Display> {
+        Ok(code_minus_bytes(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<impl Display> {
+        Ok(code_minus_code(lines))
+    }
+}
+
+aoc_utils::example_tests!(Day08);
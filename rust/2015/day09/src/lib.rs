@@ -0,0 +1,85 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::Solution;
+use itertools::Itertools;
+use winnow::{
+    ascii::{alpha1, digit1},
+    combinator::separated_pair,
+    error::InputError,
+    Parser,
+};
+
+fn parse_line<'a>() -> impl Parser<&'a str, ((String, String), usize), InputError<&'a str>> {
+    separated_pair(
+        separated_pair(alpha1.parse_to(), " to ", alpha1.parse_to()),
+        " = ",
+        digit1.parse_to::<usize>(),
+    )
+}
+
+pub struct Places {
+    count: usize,
+    distances: HashMap<(usize, usize), usize>,
+}
+
+fn parse_places(input: &str) -> Result<Places> {
+    let mut places = HashMap::<String, usize>::default();
+    let mut distances = HashMap::<(usize, usize), usize>::default();
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut parser = parse_line();
+
+        let Ok(((a, b), dist)) = parser.parse(line) else {
+            bail!("malformed input: {line}");
+        };
+
+        let [a, b] = [a, b].map(|v| {
+            let idx = places.len();
+            *places.entry(v).or_insert(idx)
+        });
+
+        distances.insert((a, b), dist);
+        distances.insert((b, a), dist);
+    }
+
+    Ok(Places {
+        count: places.len(),
+        distances,
+    })
+}
+
+fn route_lengths(places: &Places) -> impl Iterator<Item = usize> + '_ {
+    (0..places.count).permutations(places.count).map(|p| {
+        p.windows(2)
+            .map(|w| places.distances.get(&(w[0], w[1])).unwrap())
+            .sum::<usize>()
+    })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Input = Places;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_places(input)
+    }
+
+    fn part1(places: &Self::Input) -> Result<impl Display> {
+        route_lengths(places).min().context("there are no places")
+    }
+
+    fn part2(places: &Self::Input) -> Result<impl Display> {
+        route_lengths(places).max().context("there are no places")
+    }
+}
+
+aoc_utils::example_tests!(Day09);
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+fn look_and_say(mut values: &[u8]) -> Vec<u8> {
+    let mut result = vec![];
+
+    while let Some(&c) = values.first() {
+        let count = values.iter().take_while(|&&v| v == c).count();
+
+        result.extend([count as u8, c]);
+
+        values = &values[count..];
+    }
+
+    result
+}
+
+#[test]
+fn looks_and_says() {
+    assert_eq!(look_and_say(&[1]), &[1, 1]);
+    assert_eq!(look_and_say(&[1, 1]), &[2, 1]);
+    assert_eq!(look_and_say(&[2, 1]), &[1, 2, 1, 1]);
+    assert_eq!(look_and_say(&[1, 2, 1, 1]), &[1, 1, 1, 2, 2, 1]);
+    assert_eq!(look_and_say(&[1, 1, 1, 2, 2, 1]), &[3, 1, 2, 2, 1, 1]);
+}
+
+fn length_after(digits: &[u8], iterations: usize) -> usize {
+    let mut data = digits.to_vec();
+
+    for _ in 0..iterations {
+        data = look_and_say(&data);
+    }
+
+    data.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().as_bytes().iter().map(|b| *b - b'0').collect())
+    }
+
+    fn part1(digits: &Self::Input) -> Result<impl Display> {
+        Ok(length_after(digits, 40))
+    }
+
+    fn part2(digits: &Self::Input) -> Result<impl Display> {
+        Ok(length_after(digits, 50))
+    }
+}
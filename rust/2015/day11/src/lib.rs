@@ -0,0 +1,61 @@
+use core::str;
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+fn valid_password(pass: &[u8]) -> bool {
+    pass.windows(3)
+        .any(|w| w[2] == w[0] + 2 && w[1] == w[0] + 1)
+        && pass.iter().all(|&c| c != b'i' && c != b'l' && c != b'o')
+        && pass
+            .windows(2)
+            .enumerate()
+            .any(|(i, w)| w[0] == w[1] && pass[(i + 2)..].windows(2).any(|w| w[0] == w[1]))
+}
+
+fn next_password(pass: &mut [u8]) -> &[u8] {
+    loop {
+        for i in (0..pass.len()).rev() {
+            pass[i] += 1;
+
+            if pass[i] <= b'z' {
+                break;
+            } else {
+                pass[i] = b'a';
+            }
+        }
+
+        if valid_password(pass) {
+            break;
+        }
+    }
+
+    pass
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().as_bytes().to_vec())
+    }
+
+    fn part1(password: &Self::Input) -> Result<impl Display> {
+        let mut bytes = password.clone();
+
+        Ok(str::from_utf8(next_password(&mut bytes))?.to_string())
+    }
+
+    fn part2(password: &Self::Input) -> Result<impl Display> {
+        let mut bytes = password.clone();
+        next_password(&mut bytes);
+
+        Ok(str::from_utf8(next_password(&mut bytes))?.to_string())
+    }
+}
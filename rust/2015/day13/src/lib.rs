@@ -0,0 +1,116 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    iter::once,
+};
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+use itertools::Itertools;
+use winnow::{
+    ascii::{alpha1, digit1},
+    combinator::{alt, separated_pair},
+    error::InputError,
+    Parser,
+};
+
+fn line_parser<'a>() -> impl Parser<&'a str, (String, String, isize), InputError<&'a str>> {
+    (
+        alpha1,
+        " would ",
+        separated_pair(alt(("gain", "lose")), ' ', digit1.parse_to::<isize>()),
+        " happiness units by sitting next to ",
+        alpha1,
+        '.',
+    )
+        .map(
+            |(a, _, (sign, val), _, b, _): (&str, &str, (&str, isize), &str, &str, char)| {
+                (
+                    a.to_string(),
+                    b.to_string(),
+                    val * match sign {
+                        "gain" => 1,
+                        "lose" => -1,
+                        _ => panic!("unknown sign"),
+                    },
+                )
+            },
+        )
+}
+
+type Relationships = HashMap<(String, String), isize>;
+
+fn parse_relationships(input: &str) -> Result<(HashSet<String>, Relationships)> {
+    let mut names = HashSet::<String>::default();
+    let mut relationships = Relationships::default();
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut parser = line_parser();
+
+        let (a, b, delta) = parser.parse(line).map_err(|e| anyhow::format_err!("{e}"))?;
+
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+
+        names.extend([a.clone(), b.clone()]);
+        *relationships.entry((a, b)).or_default() += delta;
+    }
+
+    Ok((names, relationships))
+}
+
+fn max_happiness(names: &HashSet<String>, relationships: &Relationships) -> Option<isize> {
+    names
+        .iter()
+        .permutations(names.len())
+        .map(|permutation| {
+            let last = [*permutation.last().unwrap(), *permutation.first().unwrap()];
+
+            permutation
+                .windows(2)
+                .chain(once(last.as_slice()))
+                .map(|pair| {
+                    let [a, b] = if pair[0] <= pair[1] {
+                        [pair[0], pair[1]]
+                    } else {
+                        [pair[1], pair[0]]
+                    };
+
+                    relationships
+                        .get(&(a.into(), b.into()))
+                        .copied()
+                        .unwrap_or_default()
+                })
+                .sum::<isize>()
+        })
+        .max()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Input = (HashSet<String>, Relationships);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_relationships(input)
+    }
+
+    fn part1((names, relationships): &Self::Input) -> Result<impl Display> {
+        max_happiness(names, relationships).context("nobody to seat")
+    }
+
+    fn part2((names, relationships): &Self::Input) -> Result<impl Display> {
+        let mut names = names.clone();
+        names.insert(String::from("Me"));
+
+        max_happiness(&names, relationships).context("nobody to seat")
+    }
+}
+
+aoc_utils::example_tests!(Day13);
@@ -0,0 +1,110 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+use winnow::{
+    ascii::{alpha1, digit1},
+    error::InputError,
+    Parser,
+};
+
+pub struct Reindeer {
+    pub speed: usize,
+    pub run_time: usize,
+    pub rest_time: usize,
+}
+
+impl Reindeer {
+    pub fn distance_after(&self, secs: usize) -> usize {
+        let cycle = self.run_time + self.rest_time;
+        let full_cycles = secs / cycle;
+        let partial_cycle = secs % cycle;
+
+        (full_cycles * self.run_time + partial_cycle.min(self.run_time)) * self.speed
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, _, speed, _, run_time, _, rest_time, _) = (
+            alpha1::<&str, InputError<&str>>,
+            " can fly ",
+            digit1.parse_to(),
+            " km/s for ",
+            digit1.parse_to(),
+            " seconds, but then must rest for ",
+            digit1.parse_to(),
+            " seconds.",
+        )
+            .parse(s)
+            .map_err(|e| anyhow::format_err!("{e}"))?;
+
+        Ok(Self {
+            speed,
+            run_time,
+            rest_time,
+        })
+    }
+}
+
+const TARGET_TIME: usize = 2503;
+
+fn parse_reindeers(input: &str) -> Result<Vec<Reindeer>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_reindeers(input)
+    }
+
+    fn part1(reindeers: &Self::Input) -> Result<impl Display> {
+        reindeers
+            .iter()
+            .map(|r| r.distance_after(TARGET_TIME))
+            .max()
+            .context("there are no reindeers")
+    }
+
+    fn part2(reindeers: &Self::Input) -> Result<impl Display> {
+        let scores = (1..=TARGET_TIME)
+            .map(|secs| reindeers.iter().map(move |r| r.distance_after(secs)))
+            .fold(vec![0; reindeers.len()], |mut scores, distances| {
+                distances
+                    .enumerate()
+                    .fold((0, vec![]), |(mut prev_max, mut indices), (idx, dist)| {
+                        match dist.cmp(&prev_max) {
+                            std::cmp::Ordering::Less => (),
+                            std::cmp::Ordering::Equal => indices.push(idx),
+                            std::cmp::Ordering::Greater => {
+                                prev_max = dist;
+                                indices.clear();
+                                indices.push(idx);
+                            }
+                        }
+
+                        (prev_max, indices)
+                    })
+                    .1
+                    .into_iter()
+                    .for_each(|idx| scores[idx] += 1);
+
+                scores
+            });
+
+        scores.into_iter().max().context("there are no reindeers")
+    }
+}
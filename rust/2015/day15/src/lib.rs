@@ -0,0 +1,97 @@
+use std::{array::from_fn, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use aoc_utils::Solution;
+use nalgebra::{vector, DMatrix, DVector};
+use winnow::{
+    ascii::{alpha1, digit1},
+    combinator::{opt, separated, separated_pair},
+    error::InputError,
+    Parser,
+};
+
+struct Ingredient([isize; 5]);
+
+impl FromStr for Ingredient {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, _, ingredients): (&str, &str, Vec<(&str, isize)>) = (
+            alpha1::<_, InputError<&str>>,
+            ": ",
+            separated(
+                5..=5,
+                separated_pair(
+                    alpha1,
+                    ' ',
+                    (opt('-'), digit1.parse_to::<isize>())
+                        .map(|(s, v)| if s.is_some() { -v } else { v }),
+                ),
+                ", ",
+            ),
+        )
+            .parse(s)
+            .map_err(|e| anyhow::format_err!("{e}"))?;
+
+        Ok(Self(from_fn(|i| ingredients[i].1)))
+    }
+}
+
+fn parse_ingredients(input: &str) -> Result<DMatrix<isize>> {
+    let ingredients = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse::<Ingredient>)
+        .collect::<Result<Vec<_>>>()?;
+
+    let vecs = ingredients
+        .iter()
+        .map(|i| DVector::from_iterator(5, i.0))
+        .collect::<Vec<_>>();
+
+    Ok(DMatrix::from_columns(&vecs))
+}
+
+fn best_score(mat: &DMatrix<isize>, calories_ok: impl Fn(isize) -> bool) -> isize {
+    let spoons = 100;
+    let mut max = 0;
+
+    // FIXME: hardcoded and ugly
+    for i in 0..=spoons {
+        for j in 0..=(spoons - i) {
+            for k in 0..=(spoons - i - j) {
+                let l = spoons - i - j - k;
+
+                let v = mat * vector![i, j, k, l];
+                let score = v.into_iter().map(|&v| v.max(0)).take(4).product::<isize>();
+
+                if calories_ok(v[4]) && score > max {
+                    max = score;
+                }
+            }
+        }
+    }
+
+    max
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type Input = DMatrix<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_ingredients(input)
+    }
+
+    fn part1(ingredients: &Self::Input) -> Result<impl Display> {
+        Ok(best_score(ingredients, |_| true))
+    }
+
+    fn part2(ingredients: &Self::Input) -> Result<impl Display> {
+        Ok(best_score(ingredients, |calories| calories == 500))
+    }
+}
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{bail, Result};
+use aoc_utils::Solution;
+use winnow::{
+    ascii::{alpha1, digit1},
+    combinator::{preceded, separated, separated_pair},
+    error::InputError,
+    Parser,
+};
+
+fn objects_parser<'a>() -> impl Parser<&'a str, (String, usize), InputError<&'a str>> {
+    separated_pair(alpha1::<&str, InputError<&str>>, ": ", digit1.parse_to())
+        .map(|(name, quantity)| (name.to_string(), quantity))
+}
+
+type Objects = Vec<(String, usize)>;
+
+fn detected_objects() -> Result<HashMap<String, usize>> {
+    separated(1.., objects_parser(), '\n')
+        .parse(
+            "children: 3
+cats: 7
+samoyeds: 2
+pomeranians: 3
+akitas: 0
+vizslas: 0
+goldfish: 5
+trees: 3
+cars: 2
+perfumes: 1",
+        )
+        .map_err(|e| anyhow::format_err!("{e}"))
+}
+
+fn parse_sues(input: &str) -> Result<Vec<(usize, Objects)>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            separated_pair(
+                preceded("Sue ", digit1.parse_to::<usize>()),
+                ": ",
+                separated(1.., objects_parser(), ", "),
+            )
+            .parse(line)
+            .map_err(|e| anyhow::format_err!("{e}"))
+        })
+        .collect()
+}
+
+fn find_sue(
+    sues: &[(usize, Objects)],
+    matches: impl Fn(&str, usize, usize) -> bool,
+) -> Result<usize> {
+    let detected = detected_objects()?;
+
+    for (sue, objects) in sues {
+        if objects.iter().all(|(tp, q)| {
+            detected
+                .get(tp)
+                .is_some_and(|target| matches(tp, *q, *target))
+        }) {
+            return Ok(*sue);
+        }
+    }
+
+    bail!("no Sue matches")
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+
+    type Input = Vec<(usize, Objects)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_sues(input)
+    }
+
+    fn part1(sues: &Self::Input) -> Result<impl Display> {
+        find_sue(sues, |_, q, target| q == target)
+    }
+
+    fn part2(sues: &Self::Input) -> Result<impl Display> {
+        find_sue(sues, |tp, q, target| match tp {
+            "cats" | "trees" => q > target,
+            "pomeranians" | "goldfish" => q < target,
+            _ => target == q,
+        })
+    }
+}
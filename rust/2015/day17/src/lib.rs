@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+fn partitions(value: usize, containers: &[usize], budget: usize) -> usize {
+    if containers.is_empty() || budget == 0 {
+        return 0;
+    }
+
+    (match containers[0].cmp(&value) {
+        std::cmp::Ordering::Greater => 0,
+        std::cmp::Ordering::Equal => 1,
+        std::cmp::Ordering::Less => partitions(value - containers[0], &containers[1..], budget - 1),
+    }) + partitions(value, &containers[1..], budget)
+}
+
+fn parse_containers(input: &str) -> Result<Vec<usize>> {
+    Ok(input
+        .split('\n')
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_containers(input)
+    }
+
+    fn part1(containers: &Self::Input) -> Result<impl Display> {
+        Ok(partitions(150, containers, containers.len()))
+    }
+
+    fn part2(containers: &Self::Input) -> Result<impl Display> {
+        let (_, combinations) = (1..containers.len())
+            .map(|c| (c, partitions(150, containers, c)))
+            .find(|(_, v)| *v > 0)
+            .context("no solution for p2 found, something must be wrong")?;
+
+        Ok(combinations)
+    }
+}
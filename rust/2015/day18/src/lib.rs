@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::{
+    automaton::{BitLife, Rule},
+    IVec2, Map2D, Solution,
+};
+
+fn lights_after(map: &Map2D<bool>, steps: usize, stuck_corners: bool) -> usize {
+    let mut lights = BitLife::new(map, Rule::LIFE);
+
+    if stuck_corners {
+        let max = IVec2::new(map.width() as i32, map.height() as i32) - 1;
+
+        for corner in [IVec2::ZERO, IVec2::new(max.x, 0), IVec2::new(0, max.y), max] {
+            lights.pin(corner, true);
+        }
+    }
+
+    lights.run(steps);
+    lights.count()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+
+    type Input = Map2D<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map2D::read_str(input, |l: char| l == '#')
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        Ok(lights_after(map, 100, false))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        Ok(lights_after(map, 100, true))
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+fn parse_next_atom(input: &str) -> &str {
+    if input.starts_with('e') {
+        return &input[0..1];
+    }
+
+    if input
+        .chars()
+        .nth(1)
+        .map(|c| c != 'e' && c.is_ascii_lowercase())
+        == Some(true)
+    {
+        &input[0..2]
+    } else {
+        &input[0..1]
+    }
+}
+
+fn parse_molecule(input: &str) -> impl Iterator<Item = &str> {
+    (0..).scan(input, |state, _| {
+        if state.is_empty() {
+            return None;
+        }
+
+        let atom = parse_next_atom(state);
+        *state = &state[atom.len()..];
+
+        Some(atom)
+    })
+}
+
+type Replacements = Vec<(Vec<u8>, Vec<u8>)>;
+
+pub struct Chemistry {
+    atoms: HashMap<String, u8>,
+    replacements: Replacements,
+    molecule: Vec<u8>,
+}
+
+fn parse_input(input: &str) -> Result<Chemistry> {
+    let mut lines = input.lines();
+
+    let mut atoms = HashMap::<String, u8>::default();
+    let mut replacements = Replacements::default();
+
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let (from, to) = line
+            .split_once(" => ")
+            .context("unknown replacement format")?;
+
+        replacements.push((
+            parse_molecule(from)
+                .map(|atom| {
+                    let next = atoms.len() as u8;
+                    *atoms.entry(atom.to_string()).or_insert(next)
+                })
+                .collect(),
+            parse_molecule(to)
+                .map(|atom| {
+                    let next = atoms.len() as u8;
+                    *atoms.entry(atom.to_string()).or_insert(next)
+                })
+                .collect(),
+        ));
+    }
+
+    let molecule = parse_molecule(lines.next().context("unexpected end of input")?)
+        .map(|atom| {
+            let next = atoms.len() as u8;
+            *atoms.entry(atom.to_string()).or_insert(next)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Chemistry {
+        atoms,
+        replacements,
+        molecule,
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+
+    type Input = Chemistry;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(
+        Chemistry {
+            replacements,
+            molecule,
+            ..
+        }: &Self::Input,
+    ) -> Result<impl Display> {
+        let mut options = HashSet::new();
+
+        for (idx, _) in molecule.iter().enumerate() {
+            for (sub, by) in replacements {
+                if molecule[idx..].starts_with(sub) {
+                    options.insert(
+                        molecule
+                            .iter()
+                            .take(idx)
+                            .chain(by.iter())
+                            .chain(molecule.iter().skip(idx + sub.len()))
+                            .copied()
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+        }
+
+        Ok(options.len())
+    }
+
+    fn part2(
+        Chemistry {
+            atoms,
+            replacements,
+            molecule,
+        }: &Self::Input,
+    ) -> Result<impl Display> {
+        let mut replacements = replacements.clone();
+
+        replacements.sort_by_key(|(_, v)| v.len());
+        replacements.reverse();
+
+        // Greedily reduces the molecule, longest replacements first, and settles for the
+        // first reduction to reach a target. An exhaustive search never finishes in time.
+        fn inner(
+            replacements: &[(Vec<u8>, Vec<u8>)],
+            targets: &[Vec<u8>],
+            current: &[u8],
+            depth: usize,
+        ) -> Option<usize> {
+            if targets.iter().any(|t| t == current) {
+                return Some(depth);
+            }
+
+            for (by, sub) in replacements {
+                if sub.len() > current.len() {
+                    continue;
+                }
+
+                for i in 0..(current.len() - sub.len() + 1) {
+                    if &current[i..(i + sub.len())] == sub {
+                        let next = current
+                            .iter()
+                            .take(i)
+                            .chain(by.iter())
+                            .chain(current.iter().skip(i + sub.len()))
+                            .copied()
+                            .collect::<Vec<_>>();
+
+                        if let Some(depth) = inner(replacements, targets, &next, depth + 1) {
+                            return Some(depth);
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+
+        let electron = *atoms.get("e").context("there is no electron")?;
+        let targets = replacements
+            .iter()
+            .filter(|(k, _)| k == &vec![electron])
+            .map(|(_, v)| v.clone())
+            .collect::<Vec<_>>();
+
+        inner(&replacements, &targets, molecule, 1).context("molecule cannot be made")
+    }
+}
+
+aoc_utils::example_tests!(Day19);
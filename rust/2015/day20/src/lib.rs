@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+fn first_house(
+    target: usize,
+    houses: usize,
+    presents_per_elf: usize,
+    max_houses: Option<usize>,
+) -> Result<usize> {
+    let mut array = vec![0; houses];
+
+    for i in 0..array.len() {
+        let v = i + 1;
+
+        for j in (i..array.len())
+            .step_by(v)
+            .take(max_houses.unwrap_or(usize::MAX))
+        {
+            array[j] += v * presents_per_elf;
+        }
+    }
+
+    Ok(1 + array
+        .iter()
+        .position(|x| *x >= target)
+        .context("no solution found")?)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part1(&target: &Self::Input) -> Result<impl Display> {
+        first_house(target, target / 20, 10, None)
+    }
+
+    fn part2(&target: &Self::Input) -> Result<impl Display> {
+        first_house(target, target / 11, 11, Some(50))
+    }
+}
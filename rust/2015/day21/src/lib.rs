@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+pub struct Entity {
+    pub hp: usize,
+    pub damage: usize,
+    pub armor: usize,
+}
+
+impl Entity {
+    fn damage_against(&self, other: &Entity) -> usize {
+        self.damage.saturating_sub(other.armor).max(1)
+    }
+
+    fn equip<'a, 'b>(&'a self, items: impl IntoIterator<Item = &'b Item>) -> Self {
+        let (item_damage, item_armor) = items
+            .into_iter()
+            .fold((0, 0), |(prev_dmg, prev_armor), next| {
+                (prev_dmg + next.damage, prev_armor + next.armor)
+            });
+
+        Self {
+            hp: self.hp,
+            damage: self.damage + item_damage,
+            armor: self.armor + item_armor,
+        }
+    }
+
+    fn defeats(&self, boss: &Entity) -> bool {
+        let mut player_hp = self.hp;
+        let mut boss_hp = boss.hp;
+
+        loop {
+            boss_hp = boss_hp.saturating_sub(self.damage_against(boss));
+
+            if boss_hp == 0 {
+                return true;
+            }
+
+            player_hp = player_hp.saturating_sub(boss.damage_against(self));
+
+            if player_hp == 0 {
+                return false;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Item {
+    cost: usize,
+    damage: usize,
+    armor: usize,
+}
+
+impl Item {
+    pub const fn new_weapon(cost: usize, damage: usize) -> Self {
+        Item {
+            cost,
+            damage,
+            armor: 0,
+        }
+    }
+
+    pub const fn new_armor(cost: usize, armor: usize) -> Self {
+        Item {
+            cost,
+            armor,
+            damage: 0,
+        }
+    }
+}
+
+static WEAPONS: &[Option<Item>] = &[
+    Some(Item::new_weapon(8, 4)),
+    Some(Item::new_weapon(10, 5)),
+    Some(Item::new_weapon(25, 6)),
+    Some(Item::new_weapon(40, 7)),
+    Some(Item::new_weapon(74, 8)),
+];
+
+static ARMORS: &[Option<Item>] = &[
+    None,
+    Some(Item::new_armor(13, 1)),
+    Some(Item::new_armor(31, 2)),
+    Some(Item::new_armor(53, 3)),
+    Some(Item::new_armor(75, 4)),
+    Some(Item::new_armor(102, 5)),
+];
+
+static RINGS: &[Option<Item>] = &[
+    None,
+    Some(Item::new_weapon(25, 1)),
+    Some(Item::new_weapon(50, 2)),
+    Some(Item::new_weapon(100, 3)),
+    Some(Item::new_armor(20, 1)),
+    Some(Item::new_armor(40, 2)),
+    Some(Item::new_armor(80, 3)),
+];
+
+fn parse_boss(input: &str) -> Result<Entity> {
+    let mut stats = input.lines().filter(|line| !line.is_empty()).map(|line| {
+        line.split_once(": ")
+            .context("unknown stat format")
+            .and_then(|(_, v)| Ok(v.parse::<usize>()?))
+    });
+
+    let [Some(hp), Some(damage), Some(armor), None] = std::array::from_fn(|_| stats.next()) else {
+        anyhow::bail!("expected hit points, damage and armor");
+    };
+
+    Ok(Entity {
+        hp: hp?,
+        damage: damage?,
+        armor: armor?,
+    })
+}
+
+fn loadouts() -> impl Iterator<Item = [Option<&'static Item>; 4]> + Clone {
+    WEAPONS
+        .iter()
+        .flat_map(|w| {
+            ARMORS.iter().flat_map(move |a| {
+                RINGS.iter().flat_map(move |r1| {
+                    RINGS
+                        .iter()
+                        .map(move |r2| [w.as_ref(), a.as_ref(), r1.as_ref(), r2.as_ref()])
+                })
+            })
+        })
+        .filter(|[_, _, a, b]| a.is_some() || b.is_none())
+}
+
+fn loadout_cost(loadout: &[Option<&Item>]) -> usize {
+    loadout
+        .iter()
+        .fold(0, |p, i| p + i.map(|i| i.cost).unwrap_or_default())
+}
+
+const PLAYER: Entity = Entity {
+    hp: 100,
+    armor: 0,
+    damage: 0,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+
+    type Input = Entity;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_boss(input)
+    }
+
+    fn part1(boss: &Self::Input) -> Result<impl Display> {
+        loadouts()
+            .filter(|l| PLAYER.equip(l.iter().filter_map(|i| *i)).defeats(boss))
+            .map(|l| loadout_cost(&l))
+            .min()
+            .context("cannot win")
+    }
+
+    fn part2(boss: &Self::Input) -> Result<impl Display> {
+        loadouts()
+            .filter(|l| !PLAYER.equip(l.iter().filter_map(|i| *i)).defeats(boss))
+            .map(|l| loadout_cost(&l))
+            .max()
+            .context("cannot lose")
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+// Fewest packages first, then the smallest quantum entanglement, as (count, entanglement)
+fn least_entanglement(packages: &[usize], target: usize) -> Option<(usize, usize)> {
+    if target == 0 {
+        return Some((0, 1));
+    }
+
+    if packages.iter().copied().sum::<usize>() < target {
+        return None;
+    }
+
+    packages
+        .iter()
+        .enumerate()
+        .filter(|(_, &v)| v <= target)
+        .filter_map(|(i, &v)| {
+            least_entanglement(&packages[(i + 1)..], target - v)
+                .map(|(count, qe)| (count + 1, qe.saturating_mul(v)))
+        })
+        .min()
+}
+
+fn parse_packages(input: &str) -> Result<Vec<usize>> {
+    let mut data = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse::<usize>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    data.sort();
+    data.reverse();
+
+    Ok(data)
+}
+
+fn balanced_entanglement(packages: &[usize], groups: usize) -> Result<usize> {
+    let sum = packages.iter().copied().sum::<usize>();
+
+    least_entanglement(packages, sum / groups)
+        .map(|(_, qe)| qe)
+        .context("solution not found")
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_packages(input)
+    }
+
+    fn part1(packages: &Self::Input) -> Result<impl Display> {
+        balanced_entanglement(packages, 3)
+    }
+
+    fn part2(packages: &Self::Input) -> Result<impl Display> {
+        balanced_entanglement(packages, 4)
+    }
+}
+
+aoc_utils::example_tests!(Day24);
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+fn to_linear_index(row: usize, col: usize) -> usize {
+    let start_row = row + col - 2;
+
+    let n = start_row * (start_row + 1) / 2;
+
+    n + col - 1
+}
+
+#[test]
+fn linear_index_works() {
+    assert_eq!(to_linear_index(1, 1), 0);
+    assert_eq!(to_linear_index(4, 3), 17);
+}
+
+fn parse_position(input: &str) -> Result<(usize, usize)> {
+    let (_, position) = input
+        .trim()
+        .trim_end_matches('.')
+        .split_once("row ")
+        .context("row not found")?;
+    let (row, col) = position
+        .split_once(", column ")
+        .context("column not found")?;
+
+    Ok((row.parse()?, col.parse()?))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_position(input)
+    }
+
+    fn part1(&(row, col): &Self::Input) -> Result<impl Display> {
+        Ok((0..to_linear_index(row, col)).fold(20151125u128, |prev, _| (prev * 252533) % 33554393))
+    }
+
+    fn part2(_: &Self::Input) -> Result<impl Display> {
+        Ok("Merry Christmas!")
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::{manhattan, Dir4, IVec2, Solution, SparseGrid};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+fn parse_instructions(data: &str) -> Result<Vec<(Turn, i32)>> {
+    data.split(", ")
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(|instruction| {
+            let ch = instruction.chars().next().unwrap();
+            let dist = instruction[1..].parse::<i32>()?;
+
+            let turn = match ch {
+                'L' => Turn::Left,
+                'R' => Turn::Right,
+                _ => bail!("unknown instruction '{}'", ch),
+            };
+
+            Ok((turn, dist))
+        })
+        .collect()
+}
+
+fn walk(instructions: &[(Turn, i32)]) -> (IVec2, Option<IVec2>) {
+    let (twice, _, pos, _) = instructions.iter().fold(
+        (None, SparseGrid::new(), IVec2::ZERO, Dir4::Up),
+        |(mut twice, mut visited, pos, dir), &(turn, dist)| {
+            let dir = match turn {
+                Turn::Left => dir.turn_left(),
+                Turn::Right => dir.turn_right(),
+            };
+
+            for pos in (1..=dist).map(|i| pos + dir.vec() * i) {
+                if visited.insert(pos, ()).is_some() {
+                    twice = twice.or(Some(pos));
+                }
+            }
+
+            (twice, visited, pos + dir.vec() * dist, dir)
+        },
+    );
+
+    (pos, twice)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+
+    type Input = Vec<(Turn, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display> {
+        let (pos, _) = walk(instructions);
+
+        Ok(manhattan(pos, IVec2::ZERO))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display> {
+        let (_, twice) = walk(instructions);
+
+        Ok(manhattan(twice.context("no solution for p2")?, IVec2::ZERO))
+    }
+}
+
+aoc_utils::example_tests!(Day01);
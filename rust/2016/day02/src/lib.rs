@@ -0,0 +1,85 @@
+use std::{collections::HashMap, fmt::Display, sync::LazyLock};
+
+use anyhow::Result;
+use aoc_utils::{Dir4, IVec2, Solution};
+
+static P2_KEYPAD: LazyLock<HashMap<IVec2, char>> = LazyLock::new(|| {
+    HashMap::from_iter([
+        (IVec2::new(2, 0), '1'),
+        (IVec2::new(1, 1), '2'),
+        (IVec2::new(2, 1), '3'),
+        (IVec2::new(3, 1), '4'),
+        (IVec2::new(0, 2), '5'),
+        (IVec2::new(1, 2), '6'),
+        (IVec2::new(2, 2), '7'),
+        (IVec2::new(3, 2), '8'),
+        (IVec2::new(4, 2), '9'),
+        (IVec2::new(1, 3), 'A'),
+        (IVec2::new(2, 3), 'B'),
+        (IVec2::new(3, 3), 'C'),
+        (IVec2::new(2, 4), 'D'),
+    ])
+});
+
+fn parse_moves(input: &str) -> Result<Vec<Vec<IVec2>>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().map(|c| Ok(Dir4::try_from(c)?.vec())).collect())
+        .collect()
+}
+
+fn find_code(
+    moves: &[Vec<IVec2>],
+    start: IVec2,
+    step: impl Fn(IVec2, IVec2) -> IVec2,
+    key: impl Fn(IVec2) -> char,
+) -> String {
+    moves
+        .iter()
+        .scan(start, |pos, line| {
+            *pos = line.iter().fold(*pos, |pos, &dir| step(pos, dir));
+
+            Some(key(*pos))
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<IVec2>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<impl Display> {
+        Ok(find_code(
+            moves,
+            IVec2::new(1, 1),
+            |pos, dir| (pos + dir).clamp(IVec2::ZERO, IVec2::new(2, 2)),
+            |pos| (b'1' + (pos.x + pos.y * 3) as u8) as char,
+        ))
+    }
+
+    fn part2(moves: &Self::Input) -> Result<impl Display> {
+        Ok(find_code(
+            moves,
+            IVec2::new(0, 2),
+            |pos, dir| {
+                if P2_KEYPAD.contains_key(&(pos + dir)) {
+                    pos + dir
+                } else {
+                    pos
+                }
+            },
+            |pos| P2_KEYPAD[&pos],
+        ))
+    }
+}
+
+aoc_utils::example_tests!(Day02);
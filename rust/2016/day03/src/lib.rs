@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use aoc_utils::Solution;
+
+fn is_triangle(sides: &[usize]) -> bool {
+    sides[0] + sides[1] > sides[2]
+        && sides[0] + sides[2] > sides[1]
+        && sides[1] + sides[2] > sides[0]
+}
+
+fn parse_rows(input: &str) -> Result<Vec<Vec<usize>>> {
+    let data = input
+        .split('\n')
+        .filter(|v| !v.is_empty())
+        .map(|l| {
+            l.split(' ')
+                .filter(|v| !v.is_empty())
+                .map(|v| v.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for nums in &data {
+        ensure!(nums.len() == 3, "there should be 3 numbers per line");
+    }
+
+    Ok(data)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_rows(input)
+    }
+
+    fn part1(rows: &Self::Input) -> Result<impl Display> {
+        Ok(rows.iter().filter(|nums| is_triangle(nums)).count())
+    }
+
+    fn part2(rows: &Self::Input) -> Result<impl Display> {
+        Ok(rows.chunks_exact(3).fold(0, |mut state, rows| {
+            for i in 0..3 {
+                let nums = rows.iter().map(|v| v[i]).collect::<Vec<_>>();
+
+                if is_triangle(&nums) {
+                    state += 1;
+                }
+            }
+
+            state
+        }))
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    fmt::Display,
+};
+
+use anyhow::{bail, Result};
+use aoc_utils::Solution;
+
+fn parse_lists(input: &str) -> Result<(Vec<usize>, Vec<usize>)> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+
+    for line in input.lines() {
+        let mut split = line.split("   ");
+
+        let [Some(left), Some(right)] =
+            std::array::from_fn(|_| split.next().and_then(|v| v.parse().ok()))
+        else {
+            bail!("failed to parse input")
+        };
+
+        left_list.push(left);
+        right_list.push(right);
+    }
+
+    Ok((left_list, right_list))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lists(input)
+    }
+
+    fn part1((left_list, right_list): &Self::Input) -> Result<impl Display> {
+        let mut left_list = BinaryHeap::from(left_list.clone());
+        let mut right_list = BinaryHeap::from(right_list.clone());
+
+        Ok(std::iter::from_fn(|| left_list.pop())
+            .zip(std::iter::from_fn(|| right_list.pop()))
+            .map(|(l, r)| l.abs_diff(r))
+            .sum::<usize>())
+    }
+
+    fn part2((left_list, right_list): &Self::Input) -> Result<impl Display> {
+        let mut right_occurrences = HashMap::<usize, usize>::new();
+
+        for right in right_list {
+            *right_occurrences.entry(*right).or_default() += 1;
+        }
+
+        Ok(left_list
+            .iter()
+            .map(|i| right_occurrences.get(i).copied().unwrap_or_default() * *i)
+            .sum::<usize>())
+    }
+}
+
+aoc_utils::example_tests!(Day01);
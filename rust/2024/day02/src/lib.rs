@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<isize>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.split(' ')
+                .map(|v| v.parse::<isize>())
+                .collect::<Result<Vec<_>, _>>()
+                .with_context(|| format!("invalid report at line {}", idx + 1))
+        })
+        .collect()
+}
+
+fn is_safe(levels: impl Iterator<Item = isize> + Clone) -> bool {
+    let mut dir = 0;
+
+    levels.clone().zip(levels.skip(1)).all(|(a, b)| {
+        let dist = b - a;
+
+        if dir == 0 {
+            dir = dist.signum();
+        }
+
+        dist.signum() == dir && dist.abs() <= 3 && dist.abs() > 0
+    })
+}
+
+// The dampener tolerates a single bad level, so any one level may be left out
+fn count_valid_reports(reports: &[Vec<isize>], dampener: bool) -> usize {
+    reports
+        .iter()
+        .filter(|levels| {
+            is_safe(levels.iter().copied())
+                || (dampener
+                    && (0..levels.len()).any(|skip| {
+                        is_safe(
+                            levels
+                                .iter()
+                                .enumerate()
+                                .filter(move |&(i, _)| i != skip)
+                                .map(|(_, &v)| v),
+                        )
+                    }))
+        })
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<impl Display> {
+        Ok(count_valid_reports(reports, false))
+    }
+
+    fn part2(reports: &Self::Input) -> Result<impl Display> {
+        Ok(count_valid_reports(reports, true))
+    }
+}
+
+aoc_utils::example_tests!(Day02);
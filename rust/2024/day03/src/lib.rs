@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+use memchr::memchr2;
+use winnow::{
+    ascii::digit1,
+    combinator::{alt, empty, separated_pair, terminated},
+    error::ContextError,
+    prelude::*,
+};
+
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(usize, usize),
+}
+
+fn parse_instruction<'a>() -> impl Parser<&'a str, Instruction, ContextError> {
+    alt((
+        ("do()", empty.map(|_| Instruction::Do)),
+        ("don't()", empty.map(|_| Instruction::Dont)),
+        (
+            "mul(",
+            terminated(
+                separated_pair(digit1.parse_to::<usize>(), ',', digit1.parse_to::<usize>()),
+                ')',
+            )
+            .map(|(a, b)| Instruction::Mul(a, b)),
+        ),
+    ))
+    .map(|(_, v)| v)
+}
+
+fn parse_instructions(data: &str) -> Vec<Instruction> {
+    let mut window = data;
+    let mut instructions = vec![];
+    let mut parser = parse_instruction();
+
+    while !window.is_empty() {
+        let Some(candidate) = memchr2(b'd', b'm', window.as_bytes()) else {
+            break;
+        };
+
+        window = &window[candidate..];
+
+        if let Ok(instruction) = parser.parse_next(&mut window) {
+            instructions.push(instruction);
+        } else {
+            window = &window[1..]
+        }
+    }
+
+    instructions
+}
+
+fn sum_multiplications(instructions: &[Instruction], conditionals: bool) -> usize {
+    let mut sum = 0;
+    let mut enabled = true;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = !conditionals,
+            Instruction::Mul(a, b) if enabled => sum += a * b,
+            _ => { /* noop */ }
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_instructions(input))
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display> {
+        Ok(sum_multiplications(instructions, false))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display> {
+        Ok(sum_multiplications(instructions, true))
+    }
+}
+
+aoc_utils::example_tests!(Day03);
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use aoc_utils::Solution;
+use smallvec::SmallVec;
+
+static P1_TARGETS: &[&[u8]] = &[b"XMAS", b"SAMX"];
+static P2_TARGETS: &[&[u8]] = &[b"MAS", b"SAM"];
+
+fn count_xmas(data: &[Vec<u8>]) -> usize {
+    let rows = data.len();
+    let cols = data[0].len();
+
+    let p1_length = P1_TARGETS[0].len();
+    let mut p1_sum = 0;
+
+    let mut candidates = smallvec::SmallVec::<[[u8; P1_TARGETS[0].len()]; 4]>::with_capacity(4);
+
+    for col_idx in 0..cols {
+        for row_idx in 0..rows {
+            candidates.clear();
+
+            if col_idx <= cols - p1_length {
+                candidates.push(std::array::from_fn(|k| data[row_idx][col_idx + k]));
+            }
+
+            if row_idx <= rows - p1_length {
+                candidates.push(std::array::from_fn(|k| data[row_idx + k][col_idx]));
+            }
+
+            if col_idx <= cols - p1_length && row_idx <= rows - p1_length {
+                candidates.push(std::array::from_fn(|k| data[row_idx + k][col_idx + k]));
+            }
+
+            if row_idx <= rows - p1_length && col_idx >= p1_length - 1 {
+                candidates.push(std::array::from_fn(|k| data[row_idx + k][col_idx - k]));
+            }
+
+            p1_sum += candidates
+                .iter()
+                .filter(|c| P1_TARGETS.contains(&c.as_slice()))
+                .count();
+        }
+    }
+
+    p1_sum
+}
+
+fn count_x_mas(data: &[Vec<u8>]) -> usize {
+    let rows = data.len();
+    let cols = data[0].len();
+
+    let p2_length = P2_TARGETS[0].len();
+    let mut p2_sum = 0;
+
+    for col_idx in 0..cols {
+        for row_idx in 0..rows {
+            if col_idx <= cols - p2_length && row_idx <= rows - p2_length {
+                let candidates: [SmallVec<[u8; P2_TARGETS[0].len()]>; 2] = [
+                    (0..p2_length)
+                        .map(|k| data[row_idx + k][col_idx + k])
+                        .collect(),
+                    (0..p2_length)
+                        .map(|k| data[row_idx + k][col_idx + p2_length - 1 - k])
+                        .collect(),
+                ];
+
+                if candidates
+                    .iter()
+                    .all(|c| P2_TARGETS.contains(&c.as_slice()))
+                {
+                    p2_sum += 1
+                }
+            }
+        }
+    }
+
+    p2_sum
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let data = input
+            .lines()
+            .map(|v| v.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        ensure!(!data.is_empty(), "the puzzle is empty");
+
+        Ok(data)
+    }
+
+    fn part1(data: &Self::Input) -> Result<impl Display> {
+        Ok(count_xmas(data))
+    }
+
+    fn part2(data: &Self::Input) -> Result<impl Display> {
+        Ok(count_x_mas(data))
+    }
+}
+
+aoc_utils::example_tests!(Day04);
@@ -0,0 +1,111 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use anyhow::{bail, Result};
+use aoc_utils::Solution;
+
+type Rules = HashMap<usize, HashSet<usize>>;
+
+fn is_order_correct(pages: &[usize], rules: &Rules) -> bool {
+    for (idx, val) in pages.iter().enumerate() {
+        let Some(after) = rules.get(val) else {
+            continue;
+        };
+
+        for prev in pages.iter().take(idx) {
+            if after.contains(prev) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn order_pages(pages: &mut [usize], rules: &Rules) {
+    'correction: loop {
+        for (idx, val) in pages.iter().enumerate() {
+            let Some(rules) = rules.get(val) else {
+                continue;
+            };
+
+            for (prev_idx, prev_val) in pages.iter().enumerate().take(idx) {
+                if rules.contains(prev_val) {
+                    pages.swap(prev_idx, idx);
+                    continue 'correction;
+                }
+            }
+        }
+
+        break;
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<usize>>)> {
+    let mut lines = input.lines();
+
+    let mut rules = Rules::new();
+
+    for line in &mut lines {
+        if line.is_empty() {
+            break;
+        }
+
+        let Some((before, after)) = line.split_once('|') else {
+            bail!("format error: {line}");
+        };
+
+        let [before, after]: [usize; 2] = [before.parse()?, after.parse()?];
+
+        rules.entry(before).or_default().insert(after);
+    }
+
+    let updates = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split(',')
+                .map(|v| v.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((rules, updates))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<impl Display> {
+        Ok(updates
+            .iter()
+            .filter(|pages| is_order_correct(pages, rules))
+            .map(|pages| pages[pages.len() / 2])
+            .sum::<usize>())
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<impl Display> {
+        Ok(updates
+            .iter()
+            .filter(|pages| !is_order_correct(pages, rules))
+            .map(|pages| {
+                let mut pages = pages.clone();
+                order_pages(&mut pages, rules);
+
+                pages[pages.len() / 2]
+            })
+            .sum::<usize>())
+    }
+}
+
+aoc_utils::example_tests!(Day05);
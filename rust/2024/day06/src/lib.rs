@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::{patrol::Patrol, Dir4, IVec2, Map2D, Solution};
+
+pub struct Lab {
+    patrol: Patrol,
+    guard: IVec2,
+}
+
+impl Lab {
+    pub fn parse(map: &str) -> Result<Self> {
+        let mut guard = None;
+
+        let (obstacles, _) = Map2D::try_parse(map, |(c, pos): (char, IVec2)| {
+            match c {
+                '^' => guard = Some(pos),
+                '#' | '.' => (),
+                _ => bail!("unexpected {c:?} in the lab"),
+            }
+
+            Ok(c == '#')
+        })?;
+
+        Ok(Self {
+            patrol: Patrol::new(obstacles),
+            guard: guard.context("guard not found")?,
+        })
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Lab::parse(input)
+    }
+
+    fn part1(lab: &Self::Input) -> Result<impl Display> {
+        Ok(lab.patrol.trace(lab.guard, Dir4::Up).positions().len())
+    }
+
+    fn part2(lab: &Self::Input) -> Result<impl Display> {
+        Ok(lab.patrol.loop_obstacles(lab.guard, Dir4::Up).len())
+    }
+}
+
+aoc_utils::example_tests!(Day06);
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Ops {
+    Sum,
+    Mul,
+    Con,
+}
+
+impl Ops {
+    const ALL: [Self; 3] = [Self::Sum, Self::Mul, Self::Con];
+}
+
+fn number_ends_with(number: isize, suffix: isize) -> Option<isize> {
+    let mut mask = 10;
+    loop {
+        if (number % mask) != (suffix % mask) {
+            return None;
+        }
+
+        if suffix / mask == 0 {
+            return Some(mask);
+        }
+
+        mask = mask.checked_mul(10)?;
+    }
+}
+
+#[test]
+fn ends_with_just_werks() {
+    assert_eq!(number_ends_with(123, 456), None);
+    assert_eq!(number_ends_with(123, 3), Some(10));
+    assert_eq!(number_ends_with(1337, 37), Some(100));
+}
+
+fn check_recursive(result: isize, values: &[isize], no_con: bool) -> bool {
+    let Some(&value) = values.last() else {
+        return false;
+    };
+
+    if values.len() == 1 {
+        return value == result;
+    }
+
+    let ops = match no_con {
+        true => &Ops::ALL[0..2],
+        false => &Ops::ALL,
+    };
+
+    for op in ops.iter().rev() {
+        let Some(next) = (match op {
+            Ops::Sum if value < result => Some(result - value),
+            Ops::Mul if result % value == 0 => Some(result / value),
+            Ops::Con => number_ends_with(result, value).map(|order| result / order),
+            _ => None,
+        }) else {
+            continue;
+        };
+
+        if check_recursive(next, &values[0..(values.len() - 1)], no_con) {
+            return true;
+        }
+    }
+
+    false
+}
+
+type Equation = (isize, Vec<isize>);
+
+fn parse_equations(input: &str) -> Result<Vec<Equation>> {
+    let mut equations = vec![];
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((result, values)) = line.split_once(": ") else {
+            continue;
+        };
+
+        let result = result.parse::<isize>()?;
+        let values: Vec<isize> = values
+            .split(' ')
+            .map(|v| v.parse())
+            .collect::<Result<_, _>>()?;
+
+        equations.push((result, values));
+    }
+
+    Ok(equations)
+}
+
+fn calibration_sum(equations: &[Equation], no_con: bool) -> isize {
+    equations
+        .iter()
+        .filter(|(result, values)| check_recursive(*result, values, no_con))
+        .map(|(result, _)| result)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_equations(input)
+    }
+
+    fn part1(equations: &Self::Input) -> Result<impl Display> {
+        Ok(calibration_sum(equations, true))
+    }
+
+    fn part2(equations: &Self::Input) -> Result<impl Display> {
+        Ok(calibration_sum(equations, false))
+    }
+}
+
+aoc_utils::example_tests!(Day07);
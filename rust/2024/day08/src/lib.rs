@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::{Map2D, Solution};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+type Vec2 = glam::IVec2;
+
+pub struct City {
+    map: Map2D<char>,
+    antennas: FxHashMap<char, Vec<Vec2>>,
+}
+
+impl City {
+    fn out_of_bounds(&self, v: Vec2) -> bool {
+        self.map.get(v).is_none()
+    }
+}
+
+fn parse_city(data: &str) -> Result<City> {
+    let map = Map2D::read_str(data, |c: char| c)?;
+    let mut antennas = FxHashMap::<char, Vec<Vec2>>::default();
+
+    for (pos, &ch) in map.find(|_, &ch| ch != '.') {
+        antennas.entry(ch).or_default().push(pos);
+    }
+
+    Ok(City { map, antennas })
+}
+
+fn count_resonances(city: &City, harmonics: bool) -> usize {
+    let mut resonances = FxHashSet::<Vec2>::default();
+
+    for antennas in city.antennas.values() {
+        for (idx, &pos) in antennas.iter().enumerate() {
+            for other in &antennas[..idx] {
+                let delta = *other - pos;
+
+                if harmonics {
+                    resonances.extend(
+                        (0..)
+                            .map(|v| *other + delta * v)
+                            .take_while(|p| !city.out_of_bounds(*p)),
+                    );
+
+                    resonances.extend(
+                        (0..)
+                            .map(|v| pos - delta * v)
+                            .take_while(|p| !city.out_of_bounds(*p)),
+                    );
+                } else {
+                    resonances.extend(
+                        [*other + delta, pos - delta]
+                            .into_iter()
+                            .filter(|v| !city.out_of_bounds(*v)),
+                    );
+                }
+            }
+        }
+    }
+
+    resonances.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = City;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_city(input)
+    }
+
+    fn part1(city: &Self::Input) -> Result<impl Display> {
+        Ok(count_resonances(city, false))
+    }
+
+    fn part2(city: &Self::Input) -> Result<impl Display> {
+        Ok(count_resonances(city, true))
+    }
+}
+
+aoc_utils::example_tests!(Day08);
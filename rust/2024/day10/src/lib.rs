@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::{IVec2 as Vec2, Map2D, Solution};
+
+type Map = Map2D<u8>;
+
+pub fn find_trailheads(_: Vec2, v: &u8) -> bool {
+    *v == 0
+}
+
+// With unique peaks, the number of 9s reachable from `pos`, otherwise the number of distinct trails
+pub fn find_trail_score(map: &Map, pos: Vec2, unique_peaks: bool) -> usize {
+    if unique_peaks {
+        let reachable = map.bfs([pos], |&from, &to| to == from + 1);
+
+        return map
+            .find(|p, &height| height == 9 && reachable[p].is_some())
+            .count();
+    }
+
+    fn trails(pos: Vec2, height: u8, map: &Map) -> usize {
+        if height >= 9 {
+            return 1;
+        }
+
+        map.neighbours4(pos)
+            .filter(|&(_, _, &next)| next == height + 1)
+            .map(|(_, next_pos, &next)| trails(next_pos, next, map))
+            .sum()
+    }
+
+    match map.get(pos) {
+        Some(&0) => trails(pos, 0, map),
+        _ => 0,
+    }
+}
+
+fn parse_char(c: char) -> u8 {
+    c as u8 - b'0'
+}
+
+#[test]
+fn example_werks() {
+    let map = Map::read_str(
+        "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        parse_char,
+    )
+    .unwrap();
+
+    let score = map
+        .find(find_trailheads)
+        .map(|(p, _)| find_trail_score(&map, p, true))
+        .sum::<usize>();
+
+    assert_eq!(score, 36);
+}
+
+#[test]
+fn finds_correct_peaks() {
+    let data = Map::read_str(&aoc_utils::input::read(2024, 10, None).unwrap(), parse_char).unwrap();
+
+    assert_eq!(find_trail_score(&data, Vec2::new(46, 32), true), 3);
+}
+
+fn total_trail_score(map: &Map, unique_peaks: bool) -> usize {
+    map.find(find_trailheads)
+        .map(|(p, _)| find_trail_score(map, p, unique_peaks))
+        .sum::<usize>()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::read_str(input, parse_char)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        Ok(total_trail_score(map, true))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        Ok(total_trail_score(map, false))
+    }
+}
+
+aoc_utils::example_tests!(Day10);
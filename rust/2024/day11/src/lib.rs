@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+use rustc_hash::FxHashMap as HashMap;
+
+fn split_even_digits(number: usize) -> Option<(usize, usize)> {
+    match number.ilog10() + 1 {
+        digits if digits % 2 == 0 => {
+            let mask = 10usize.pow(digits / 2);
+
+            Some((number / mask, number % mask))
+        }
+        _ => None,
+    }
+}
+
+fn count_stones_after_steps(
+    number: usize,
+    steps: usize,
+    dynamic: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if steps == 0 {
+        return 1;
+    }
+
+    if let Some(v) = dynamic.get(&(number, steps)) {
+        return *v;
+    }
+
+    let v = if number == 0 {
+        count_stones_after_steps(1, steps - 1, dynamic)
+    } else if let Some((a, b)) = split_even_digits(number) {
+        count_stones_after_steps(a, steps - 1, dynamic)
+            + count_stones_after_steps(b, steps - 1, dynamic)
+    } else {
+        count_stones_after_steps(number * 2024, steps - 1, dynamic)
+    };
+
+    dynamic.insert((number, steps), v);
+
+    v
+}
+
+fn count_stones(stones: &[usize], steps: usize) -> usize {
+    let mut dynamic = HashMap::default();
+
+    stones
+        .iter()
+        .map(|&v| count_stones_after_steps(v, steps, &mut dynamic))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .trim()
+            .split(' ')
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<impl Display> {
+        Ok(count_stones(stones, 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<impl Display> {
+        Ok(count_stones(stones, 75))
+    }
+}
+
+aoc_utils::example_tests!(Day11);
@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::{Map2D, Solution};
+
+type Map = Map2D<u8>;
+
+fn calculate_fence_price(map: &Map) -> (usize, usize) {
+    map.regions(|a, b| a == b)
+        .1
+        .iter()
+        .fold((0, 0), |(nondiscounted, discounted), region| {
+            (
+                nondiscounted + region.perimeter * region.area,
+                discounted + region.sides * region.area,
+            )
+        })
+}
+
+#[test]
+fn example_just_werks() {
+    let map = Map::read_str(
+        "AAAA
+BBCD
+BBCC
+EEEC",
+        |c: char| c as u8 - b'A',
+    )
+    .unwrap();
+
+    assert_eq!(calculate_fence_price(&map), (140, 80));
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::read_str(input, |c: char| c as u8 - b'A')
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        Ok(calculate_fence_price(map).0)
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        Ok(calculate_fence_price(map).1)
+    }
+}
+
+aoc_utils::example_tests!(Day12);
@@ -0,0 +1,112 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use aoc_utils::{Solution, Wrapping};
+use glam::{IVec2, Vec2};
+use winnow::{
+    ascii::digit1,
+    combinator::{opt, preceded, separated_pair},
+    error::InputError,
+    Parser,
+};
+
+pub struct Robot {
+    start_pos: IVec2,
+    velocity: IVec2,
+}
+
+impl Robot {
+    pub fn position_after_steps(&self, steps: i32, space: &Wrapping) -> IVec2 {
+        space.wrap(self.start_pos + steps * self.velocity)
+    }
+}
+
+impl FromStr for Robot {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn signed_digit<'a>() -> impl Parser<&'a str, i32, InputError<&'a str>> {
+            (opt('-'), digit1.parse_to::<i32>()).map(|(s, v)| if s.is_some() { -v } else { v })
+        }
+
+        let ((px, py), (vx, vy)) = separated_pair(
+            preceded(
+                "p=",
+                separated_pair(digit1.parse_to::<i32>(), ',', digit1.parse_to::<i32>()),
+            ),
+            ' ',
+            preceded("v=", separated_pair(signed_digit(), ',', signed_digit())),
+        )
+        .parse(s)
+        .map_err(|e| anyhow::format_err!("{e}"))?;
+
+        Ok(Self {
+            start_pos: IVec2::new(px, py),
+            velocity: IVec2::new(vx, vy),
+        })
+    }
+}
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>> {
+    input.lines().map(str::parse).collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_robots(input)
+    }
+
+    fn part1(robots: &Self::Input) -> Result<impl Display> {
+        let space = Wrapping::new(IVec2::new(101, 103));
+
+        Ok(robots
+            .iter()
+            .fold(vec![0; 4], |mut score, robot| {
+                let pos = robot.position_after_steps(100, &space);
+
+                if let Some(quadrant) = space.quadrant(pos) {
+                    score[quadrant] += 1;
+                }
+
+                score
+            })
+            .into_iter()
+            .product::<i32>())
+    }
+
+    fn part2(robots: &Self::Input) -> Result<impl Display> {
+        let space = Wrapping::new(IVec2::new(101, 103));
+
+        for secs in 1.. {
+            let positions = robots
+                .iter()
+                .map(|r| r.position_after_steps(secs, &space))
+                .collect::<Vec<_>>();
+
+            let mean = positions
+                .iter()
+                .map(|v| Vec2::new(v.x as f32, v.y as f32))
+                .sum::<Vec2>()
+                / positions.len() as f32;
+            let stdev = (positions
+                .iter()
+                .map(|p| (Vec2::new(p.x as f32, p.y as f32) - mean).length_squared())
+                .sum::<f32>()
+                / positions.len() as f32)
+                .sqrt();
+
+            if stdev < 30. {
+                return Ok(secs);
+            }
+        }
+
+        unreachable!()
+    }
+}
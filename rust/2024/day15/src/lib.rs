@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::{
+    sokoban::{self, Crate, Sokoban},
+    Dir4, IVec2, Map2D, Solution,
+};
+
+pub struct Warehouse {
+    map: Map2D<bool>,
+    crates: Vec<IVec2>,
+    player: IVec2,
+    instructions: Vec<Dir4>,
+}
+
+fn parse_warehouse(input: &str) -> Result<Warehouse> {
+    let mut crates = vec![];
+    let mut player = None;
+
+    let (map, rest) = Map2D::try_parse(input, |(c, pos): (char, IVec2)| {
+        match c {
+            '@' => player = Some(pos),
+            'O' => crates.push(pos),
+            '#' | '.' => (),
+            _ => bail!("unexpected {c:?} in the warehouse"),
+        }
+
+        Ok(c == '#')
+    })?;
+
+    let player = player.context("the warehouse has no robot")?;
+
+    let instructions = sokoban::parse_moves(rest)?;
+
+    Ok(Warehouse {
+        map,
+        crates,
+        player,
+        instructions,
+    })
+}
+
+fn gps_sum(warehouse: &Warehouse, width_factor: i32) -> i32 {
+    let Warehouse {
+        map,
+        crates,
+        player,
+        instructions,
+    } = warehouse;
+
+    let scale = IVec2::new(width_factor, 1);
+    let mut sokoban = Sokoban::new(
+        map.scale(width_factor as usize, 1),
+        crates.iter().map(|pos| Crate {
+            pos: pos * scale,
+            size: scale,
+        }),
+        player * scale,
+    );
+
+    sokoban.run(instructions);
+
+    sokoban
+        .crates()
+        .iter()
+        .map(|b| b.pos.y * 100 + b.pos.x)
+        .sum::<i32>()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_warehouse(input)
+    }
+
+    fn part1(warehouse: &Self::Input) -> Result<impl Display> {
+        Ok(gps_sum(warehouse, 1))
+    }
+
+    fn part2(warehouse: &Self::Input) -> Result<impl Display> {
+        Ok(gps_sum(warehouse, 2))
+    }
+}
+
+aoc_utils::example_tests!(Day15);
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::{search, FxHashSet as HashSet, IVec2, Map2D, Solution};
+
+// Moving forward costs 1 and turning in place costs 1000
+fn moves(
+    &(pos, dir): &(IVec2, IVec2),
+    map: &Map2D<bool>,
+) -> impl Iterator<Item = ((IVec2, IVec2), usize)> {
+    let forward = (map.get(pos + dir) == Some(&false)).then_some(((pos + dir, dir), 1));
+    let turns = [dir.perp(), -dir.perp()].map(|dir| ((pos, dir), 1000));
+
+    forward.into_iter().chain(turns)
+}
+
+fn find_least_score(pos: IVec2, dir: IVec2, map: &Map2D<bool>, end: IVec2) -> Option<usize> {
+    search::dijkstra(
+        (pos, dir),
+        |state| moves(state, map),
+        |&(pos, _)| pos == end,
+    )
+    .map(|found| found.cost)
+}
+
+// Tiles on any best path, whichever way the reindeer faces on them
+fn find_tile_count(start: IVec2, dir: IVec2, map: &Map2D<bool>, end: IVec2) -> Option<usize> {
+    let found = search::dijkstra_all(
+        (start, dir),
+        |state| moves(state, map),
+        |&(pos, _)| pos == end,
+    )?;
+    let predecessors = found.predecessors?;
+
+    Some(
+        predecessors
+            .on_optimal_paths()
+            .map(|&(pos, _)| pos)
+            .collect::<HashSet<_>>()
+            .len(),
+    )
+}
+
+fn parse_maze(input: &str) -> Result<(IVec2, IVec2, Map2D<bool>)> {
+    let mut start_pos = IVec2::default();
+    let mut end_pos = IVec2::default();
+
+    let map = Map2D::read_str(input, |(c, pos): (char, IVec2)| {
+        match c {
+            'S' => start_pos = pos,
+            'E' => end_pos = pos,
+            _ => (),
+        };
+
+        c == '#'
+    })?;
+
+    Ok((start_pos, end_pos, map))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input = (IVec2, IVec2, Map2D<bool>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_maze(input)
+    }
+
+    fn part1(&(start_pos, end_pos, ref map): &Self::Input) -> Result<impl Display> {
+        find_least_score(start_pos, IVec2::new(1, 0), map, end_pos).context("no solution found")
+    }
+
+    fn part2(&(start_pos, end_pos, ref map): &Self::Input) -> Result<impl Display> {
+        find_tile_count(start_pos, IVec2::new(1, 0), map, end_pos).context("no solution found")
+    }
+}
+
+aoc_utils::example_tests!(Day16);
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::{AStarOptions, IVec2, Map2D, Params, Solution};
+
+// The bytes in falling order, onto a square memory space
+pub struct Memory {
+    bytes: Vec<IVec2>,
+    size: usize,
+    // How many bytes have fallen by the time part 1 starts walking
+    fallen: usize,
+}
+
+fn parse_bytes(input: &str) -> Result<Vec<IVec2>> {
+    input
+        .lines()
+        .map(|line| {
+            let coords = line
+                .split(',')
+                .map(|v| v.parse::<i32>())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if coords.len() != 2 {
+                bail!("expected two coordinate components");
+            }
+
+            Ok(IVec2::new(coords[0], coords[1]))
+        })
+        .collect()
+}
+
+fn corrupted_map(bytes: &[IVec2], size: usize) -> Map2D<bool> {
+    bytes.iter().fold(Map2D::new(size, size), |mut state, &p| {
+        state.set(p, true);
+
+        state
+    })
+}
+
+fn shortest_path(map: &Map2D<bool>) -> Option<Vec<IVec2>> {
+    map.a_star(AStarOptions::new(
+        IVec2::new(0, 0),
+        IVec2::new(map.width() as i32 - 1, map.height() as i32 - 1),
+    ))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Memory {
+            bytes: parse_bytes(input)?,
+            size: 71,
+            fallen: 1024,
+        })
+    }
+
+    fn parse_example(input: &str, params: &Params) -> Result<Self::Input> {
+        Ok(Memory {
+            bytes: parse_bytes(input)?,
+            size: params.get("size")?,
+            fallen: params.get("fallen")?,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> Result<impl Display> {
+        let data = &memory.bytes;
+        let map = corrupted_map(&data[..memory.fallen.min(data.len())], memory.size);
+
+        Ok(shortest_path(&map).context("exit is unreachable")?.len() - 1)
+    }
+
+    fn part2(memory: &Self::Input) -> Result<impl Display> {
+        let data = &memory.bytes;
+        let search = data.iter().enumerate().map(|(i, _)| i).collect::<Vec<_>>();
+
+        let p2 = search.binary_search_by(|&idx| {
+            match shortest_path(&corrupted_map(&data[..=idx], memory.size)) {
+                Some(_) => std::cmp::Ordering::Less,
+                None => std::cmp::Ordering::Greater,
+            }
+        });
+
+        let byte = data.get(p2.unwrap_err()).context("exit is never cut off")?;
+
+        Ok(format!("{},{}", byte.x, byte.y))
+    }
+}
+
+aoc_utils::example_tests!(Day18);
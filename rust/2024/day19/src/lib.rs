@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+use rustc_hash::FxHashMap as HashMap;
+
+fn possible_combinations(
+    combo: &str,
+    towels: &[impl AsRef<str>],
+    cache: &mut HashMap<String, usize>,
+) -> usize {
+    if combo.is_empty() {
+        return 1;
+    }
+
+    if let Some(v) = cache.get(combo) {
+        return *v;
+    }
+
+    let count = towels
+        .iter()
+        .map(|t| {
+            if combo.starts_with(t.as_ref()) {
+                possible_combinations(&combo[t.as_ref().len()..], towels, cache)
+            } else {
+                0
+            }
+        })
+        .sum::<usize>();
+
+    cache.insert(combo.into(), count);
+
+    count
+}
+
+pub struct Onsen {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+fn parse_onsen(input: &str) -> Onsen {
+    let mut lines = input.lines();
+
+    let towels = lines
+        .by_ref()
+        .take_while(|v| !v.is_empty())
+        .flat_map(|line| line.split(", "))
+        .map(String::from)
+        .collect();
+
+    Onsen {
+        towels,
+        designs: lines.map(String::from).collect(),
+    }
+}
+
+fn design_combinations(onsen: &Onsen) -> impl Iterator<Item = usize> + '_ {
+    let mut cache = HashMap::default();
+
+    onsen.designs.iter().map(move |design| {
+        cache.clear();
+
+        possible_combinations(design, &onsen.towels, &mut cache)
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    type Input = Onsen;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_onsen(input))
+    }
+
+    fn part1(onsen: &Self::Input) -> Result<impl Display> {
+        Ok(design_combinations(onsen)
+            .map(|combos| combos.min(1))
+            .sum::<usize>())
+    }
+
+    fn part2(onsen: &Self::Input) -> Result<impl Display> {
+        Ok(design_combinations(onsen).sum::<usize>())
+    }
+}
+
+aoc_utils::example_tests!(Day19);
@@ -0,0 +1,194 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use aoc_utils::{FxHashMap as HashMap, IVec2, Map2D, Params, Solution};
+
+// Counts cheats by how much time they save, from the distances of every track cell to both ends
+fn find_shortcuts(
+    map: &Map2D<bool>,
+    start: IVec2,
+    end: IVec2,
+    max_cheat_steps: usize,
+) -> Option<HashMap<usize, usize>> {
+    let from_start = map.bfs([start], |_, &wall| !wall);
+    let to_end = map.bfs([end], |_, &wall| !wall);
+    let fastest = from_start[end]?;
+
+    let max = max_cheat_steps as i32;
+    let mut cheats = HashMap::<usize, usize>::default();
+
+    for (pos, steps) in from_start.find(|_, steps| steps.is_some()) {
+        let steps = steps.unwrap_or_default();
+
+        for dy in -max..=max {
+            let reach = max - dy.abs();
+
+            for dx in -reach..=reach {
+                let Some(&Some(remaining)) = to_end.get(pos + IVec2::new(dx, dy)) else {
+                    continue;
+                };
+
+                let length = steps + (dx.abs() + dy.abs()) as u32 + remaining;
+
+                if length < fastest {
+                    *cheats.entry((fastest - length) as usize).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    Some(cheats)
+}
+
+#[cfg(test)]
+fn load_example() -> Racetrack {
+    parse_racetrack(include_str!("../examples/example.txt"), 0).unwrap()
+}
+
+#[test]
+fn p1_example_works() {
+    let Racetrack {
+        start, end, map, ..
+    } = load_example();
+
+    let shortcut_counts = find_shortcuts(&map, start, end, 2).unwrap();
+
+    for (by, expected) in [
+        (2, 14),
+        (4, 14),
+        (6, 2),
+        (8, 4),
+        (10, 2),
+        (12, 3),
+        (20, 1),
+        (36, 1),
+        (38, 1),
+        (40, 1),
+        (64, 1),
+    ] {
+        assert_eq!(
+            shortcut_counts.get(&by).copied().unwrap_or_default(),
+            expected,
+            "there should be {} shortcuts for {} picoseconds",
+            expected,
+            by
+        )
+    }
+}
+
+#[test]
+fn p2_example_works() {
+    let Racetrack {
+        start, end, map, ..
+    } = load_example();
+
+    let shortcut_counts = find_shortcuts(&map, start, end, 21).unwrap();
+
+    for (by, expected) in [
+        (50, 32),
+        (52, 31),
+        (54, 29),
+        (56, 39),
+        (58, 25),
+        (60, 23),
+        (62, 20),
+        (64, 19),
+        (66, 12),
+        (68, 14),
+        (70, 12),
+        (72, 22),
+        (74, 4),
+        (76, 3),
+    ] {
+        assert_eq!(
+            shortcut_counts.get(&by).copied().unwrap_or_default(),
+            expected,
+            "there should be {} shortcuts for {} picoseconds",
+            expected,
+            by
+        )
+    }
+}
+
+pub struct Racetrack {
+    start: IVec2,
+    end: IVec2,
+    map: Map2D<bool>,
+    // Cheats have to save at least this many picoseconds to count
+    min_saving: usize,
+}
+
+fn parse_racetrack(input: &str, min_saving: usize) -> Result<Racetrack> {
+    let mut start = IVec2::ZERO;
+    let mut end = IVec2::ZERO;
+
+    let map = Map2D::read_str(input, |(c, pos)| {
+        match c {
+            '#' => return true,
+            'S' => {
+                start = pos;
+            }
+            'E' => {
+                end = pos;
+            }
+            _ => (),
+        };
+
+        false
+    })?;
+
+    Ok(Racetrack {
+        start,
+        end,
+        map,
+        min_saving,
+    })
+}
+
+fn count_good_shortcuts(racetrack: &Racetrack, max_cheat_steps: usize) -> Result<usize> {
+    let Racetrack {
+        start,
+        end,
+        map,
+        min_saving,
+    } = racetrack;
+
+    Ok(find_shortcuts(map, *start, *end, max_cheat_steps)
+        .context("no base solution found")?
+        .into_iter()
+        .filter_map(|(improvement, count)| {
+            if improvement >= *min_saving {
+                Some(count)
+            } else {
+                None
+            }
+        })
+        .sum::<usize>())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Input = Racetrack;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_racetrack(input, 100)
+    }
+
+    fn parse_example(input: &str, params: &Params) -> Result<Self::Input> {
+        parse_racetrack(input, params.get("min_saving")?)
+    }
+
+    fn part1(racetrack: &Self::Input) -> Result<impl Display> {
+        count_good_shortcuts(racetrack, 2)
+    }
+
+    fn part2(racetrack: &Self::Input) -> Result<impl Display> {
+        count_good_shortcuts(racetrack, 20)
+    }
+}
+
+aoc_utils::example_tests!(Day20);
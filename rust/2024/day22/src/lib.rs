@@ -0,0 +1,108 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use anyhow::{Context, Result};
+use aoc_utils::Solution;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+fn rotate_secret(secret: usize) -> usize {
+    fn prune(a: usize) -> usize {
+        a % 16777216
+    }
+
+    let secret = prune(secret ^ (secret << 6));
+    let secret = prune(secret ^ (secret >> 5));
+
+    prune(secret ^ (secret << 11))
+}
+
+#[test]
+fn p1_rotate_example() {
+    let mut a = 123;
+
+    for expected in [
+        15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254,
+    ] {
+        a = rotate_secret(a);
+
+        assert_eq!(a, expected);
+    }
+}
+
+fn seq_tables(seeds: &[usize]) -> FxHashMap<[i8; 4], usize> {
+    let mut result = FxHashMap::default();
+
+    seeds.iter().for_each(|&s| {
+        let mut encountered = FxHashSet::default();
+        let mut diffs = VecDeque::new();
+        let mut prev = (s % 10) as i8;
+
+        (0..2000)
+            .scan(s, |state, _| {
+                *state = rotate_secret(*state);
+                Some((*state % 10) as i8)
+            })
+            .for_each(|next| {
+                while diffs.len() >= 4 {
+                    diffs.pop_front();
+                }
+                diffs.push_back(next - prev);
+
+                prev = next;
+
+                if diffs.len() == 4 {
+                    let seq = std::array::from_fn::<i8, 4, _>(|i| diffs[i]);
+
+                    if encountered.insert(seq) {
+                        *result.entry(seq).or_default() += next as usize;
+                    }
+                }
+            });
+    });
+
+    result
+}
+#[test]
+fn p2_example() {
+    let seeds = vec![1, 2, 3, 2024];
+    let sequence = [-2, 1, -1, 3];
+
+    assert_eq!(seq_tables(&seeds).get(&sequence).copied(), Some(23));
+}
+
+fn parse_seeds(input: &str) -> Result<Vec<usize>> {
+    Ok(input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::parse::<usize>)
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_seeds(input)
+    }
+
+    fn part1(seeds: &Self::Input) -> Result<impl Display> {
+        Ok(seeds
+            .iter()
+            .map(|&s| (0..2000).fold(s, |s, _| rotate_secret(s)))
+            .sum::<usize>())
+    }
+
+    fn part2(seeds: &Self::Input) -> Result<impl Display> {
+        seq_tables(seeds)
+            .values()
+            .max()
+            .copied()
+            .context("there are no buyers")
+    }
+}
+
+aoc_utils::example_tests!(Day22);
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::Solution;
+use rustc_hash::{FxHashMap, FxHashSet as HashSet};
+
+type Network = FxHashMap<String, HashSet<String>>;
+
+fn parse_network(input: &str) -> Result<Network> {
+    input
+        .lines()
+        .try_fold(Network::default(), |mut state, line| -> Result<_> {
+            if !line.is_empty() {
+                let mut line = line.split('-').map(String::from);
+
+                let [Some(a), Some(b), None] = std::array::from_fn(|_| line.next()) else {
+                    bail!("wrong format");
+                };
+
+                state.entry(a.clone()).or_default().insert(b.clone());
+                state.entry(b).or_default().insert(a);
+            }
+
+            Ok(state)
+        })
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_network(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        let mut known = HashSet::default();
+
+        for (a, values) in map.iter().filter(|(k, _)| k.starts_with('t')) {
+            for b in values.iter() {
+                for c in &map[b] {
+                    if values.contains(c) {
+                        let mut triplet = [a.as_str(), b.as_str(), c.as_str()];
+
+                        triplet.sort();
+
+                        known.insert(triplet);
+                    }
+                }
+            }
+        }
+
+        Ok(known.len())
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        let mut groups: Vec<HashSet<String>> = vec![];
+
+        for (a, v) in map.iter() {
+            for group in groups.iter_mut() {
+                if group.intersection(v).count() == group.len() {
+                    group.insert(a.clone());
+                }
+            }
+
+            for b in v {
+                if !groups.iter().any(|g| g.contains(a) && g.contains(b)) {
+                    groups.push(HashSet::from_iter([a.clone(), b.clone()]));
+                }
+            }
+        }
+
+        let mut max_group = groups
+            .iter()
+            .max_by_key(|g| g.len())
+            .context("no groups found")?
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        max_group.sort();
+
+        Ok(max_group.join(","))
+    }
+}
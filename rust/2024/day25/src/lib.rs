@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_utils::Solution;
+
+type Schematics = (Vec<[usize; 5]>, Vec<[usize; 5]>);
+
+fn parse_schematics(data: &str) -> Schematics {
+    data.split("\n\n")
+        .fold((vec![], vec![]), |(mut keys, mut locks), schema| {
+            let schema = schema
+                .trim()
+                .split('\n')
+                .map(|v| v.as_bytes())
+                .collect::<Vec<_>>();
+
+            if schema[0] == b"#####" {
+                locks.push(std::array::from_fn::<usize, 5, _>(|i| {
+                    schema.iter().position(|v| v[i] == b'.').unwrap() - 1
+                }));
+            } else {
+                keys.push(std::array::from_fn::<usize, 5, _>(|i| {
+                    schema.iter().rev().position(|v| v[i] == b'.').unwrap() - 1
+                }));
+            }
+
+            (keys, locks)
+        })
+}
+
+fn count_fitting_combos((keys, locks): &Schematics) -> usize {
+    keys.iter()
+        .map(|k| {
+            locks
+                .iter()
+                .filter(|l| k.iter().zip(l.iter()).all(|(&k, &l)| k + l <= 5))
+                .count()
+        })
+        .sum::<usize>()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    type Input = Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_schematics(input))
+    }
+
+    fn part1(schematics: &Self::Input) -> Result<impl Display> {
+        Ok(count_fitting_combos(schematics))
+    }
+
+    fn part2(_: &Self::Input) -> Result<impl Display> {
+        Ok("Merry Christmas!")
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::input;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+// The site asks automated tools to space out their requests, so the time of the last one is kept
+// next to the cached inputs and shared between runs
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+const STAMP_FILE: &str = ".last-fetch";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Display for Fetched {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fetched::Cached(path) => write!(f, "already in {}", path.display()),
+            Fetched::Downloaded(path) => write!(f, "downloaded to {}", path.display()),
+        }
+    }
+}
+
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache_dir: cache_dir.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    // Inputs are cached where `aoc_utils::input` looks for them
+    pub fn from_env() -> Self {
+        Self::new(
+            &std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into()),
+            std::env::var(SESSION_VAR).ok(),
+            input::inputs_dir(),
+        )
+    }
+
+    // A cached input is never downloaded again
+    pub fn fetch(&self, year: u16, day: u8) -> Result<Fetched> {
+        let path = input::day_path(&self.cache_dir, year, day);
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        if !is_unlocked(year, day) {
+            bail!("{year} day {day} is not unlocked yet");
+        }
+
+        let session = self
+            .session
+            .as_deref()
+            .with_context(|| format!("{SESSION_VAR} should hold the session cookie"))?;
+
+        self.wait_for_slot()?;
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+
+        // Failed requests count too
+        self.stamp()?;
+
+        let body = match response {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("failed to read the body of {url}"))?,
+            Err(ureq::Error::Status(429, response)) => bail!(
+                "rate limited by {}, retry after {}s",
+                self.base_url,
+                response.header("Retry-After").unwrap_or("?")
+            ),
+            Err(ureq::Error::Status(code, response)) => bail!(
+                "{url} returned {code}: {}",
+                response.into_string().unwrap_or_default().trim()
+            ),
+            Err(e) => return Err(e).with_context(|| format!("failed to fetch {url}")),
+        };
+
+        if body.is_empty() {
+            bail!("{url} returned an empty input");
+        }
+
+        write_atomically(&path, &body)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn stamp_path(&self) -> PathBuf {
+        self.cache_dir.join(STAMP_FILE)
+    }
+
+    fn wait_for_slot(&self) -> Result<()> {
+        let Ok(stamp) = std::fs::read_to_string(self.stamp_path()) else {
+            return Ok(());
+        };
+        let last = Duration::from_millis(stamp.trim().parse().unwrap_or(0));
+
+        if let Some(wait) = (last + MIN_INTERVAL).checked_sub(now()) {
+            std::thread::sleep(wait);
+        }
+
+        Ok(())
+    }
+
+    fn stamp(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.cache_dir)?;
+        std::fs::write(self.stamp_path(), now().as_millis().to_string())
+            .with_context(|| format!("failed to write {}", self.stamp_path().display()))
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    now() >= unlocks_at(year, day)
+}
+
+// Puzzles unlock at midnight EST (05:00 UTC) on their day of December
+fn unlocks_at(year: u16, day: u8) -> Duration {
+    // Days since the epoch of a date, from Howard Hinnant's `days_from_civil`
+    let (y, m, d) = (year as i64, 12, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+// A partial download must never look like a cached input
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let dir = path.parent().context("input path without a directory")?;
+    std::fs::create_dir_all(dir)?;
+
+    let tmp = path.with_extension("part");
+    std::fs::write(&tmp, contents).with_context(|| format!("failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path).with_context(|| format!("failed to write {}", path.display()))
+}
+
+// Answers each connection with the next canned response, and hands back the raw requests
+#[cfg(test)]
+fn stub_server(responses: Vec<String>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+
+            // Up to the blank line that ends the headers
+            while reader.read_line(&mut request).unwrap() > 2 {}
+
+            stream.write_all(response.as_bytes()).unwrap();
+            requests.push(request);
+        }
+
+        requests
+    });
+
+    (url, handle)
+}
+
+#[cfg(test)]
+fn response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n{headers}\r\n{body}",
+        body.len()
+    )
+}
+
+#[cfg(test)]
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    dir
+}
+
+#[test]
+fn inputs_are_downloaded_once() {
+    let dir = scratch_dir("once");
+    let (url, server) = stub_server(vec![response("200 OK", "", "1 2 3\n")]);
+    let fetcher = Fetcher::new(&url, Some("secret".into()), &dir);
+    let path = input::day_path(&dir, 2024, 1);
+
+    assert_eq!(
+        fetcher.fetch(2024, 1).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(
+        fetcher.fetch(2024, 1).unwrap(),
+        Fetched::Cached(path.clone())
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+    let requests = server.join().unwrap();
+
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    assert!(requests[0].contains("session=secret"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_downloads_are_not_cached() {
+    let dir = scratch_dir("failed");
+    let (url, server) = stub_server(vec![response(
+        "429 Too Many Requests",
+        "Retry-After: 60\r\n",
+        "",
+    )]);
+    let fetcher = Fetcher::new(&url, Some("secret".into()), &dir);
+
+    let err = fetcher.fetch(2015, 7).unwrap_err();
+
+    assert!(err.to_string().contains("retry after 60s"), "{err}");
+    assert!(!input::day_path(&dir, 2015, 7).exists());
+    assert!(Fetcher::new(&url, None, &dir).fetch(2015, 7).is_err());
+
+    server.join().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn puzzles_unlock_at_midnight_est() {
+    // 2024-12-01T05:00:00Z
+    assert_eq!(unlocks_at(2024, 1), Duration::from_secs(1733029200));
+}
@@ -0,0 +1,356 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_utils::{
+    input::{self, Source},
+    Answers, BenchResults, DynSolution, Part, Verdict,
+};
+use clap::{Parser, Subcommand};
+use fetch::Fetcher;
+
+mod fetch;
+mod readme;
+mod scaffold;
+mod solutions;
+
+#[derive(Parser)]
+struct Options {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a year, or of a single day
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only run this part
+        #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or from stdin with `-`
+        #[arg(long, short)]
+        input: Option<String>,
+    },
+    /// Check the answers of every part against the recorded ones
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Time parse, part 1 and part 2 over several runs
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// How many times each stage is run
+        #[arg(long, short, default_value_t = 10)]
+        runs: usize,
+        /// Where to write the results
+        #[arg(long, short, default_value = "bench.json")]
+        output: PathBuf,
+        /// Results of a previous run to compare against
+        #[arg(long, short)]
+        baseline: Option<PathBuf>,
+        /// Slowdown of a median, in percent, that counts as a regression
+        #[arg(long, short, default_value_t = 10.)]
+        threshold: f64,
+    },
+    /// Download the inputs of a year, or of a single day, unless they are already there
+    Fetch {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// List the available solutions
+    List,
+    /// Regenerate the progress table of the README from the verified answers
+    Readme {
+        /// Also list the median runtime of every day, from the results of `bench`
+        #[arg(long, short)]
+        runtimes: Option<PathBuf>,
+        /// Fail instead of writing when the README is out of date
+        #[arg(long, short)]
+        check: bool,
+    },
+    /// Create the crate of a new day and register it in the workspace
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static dyn DynSolution>> {
+    let selected = solutions::SOLUTIONS
+        .iter()
+        .copied()
+        .filter(|s| year.is_none_or(|y| s.year() == y) && day.is_none_or(|d| s.day() == d))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => bail!("there is no solution for {year} day {day}"),
+            (Some(year), None) => bail!("there are no solutions for {year}"),
+            (None, _) => bail!("there are no solutions"),
+        }
+    }
+
+    Ok(selected)
+}
+
+fn run(year: u16, day: Option<u8>, part: Option<Part>, input: Option<&str>) -> Result<()> {
+    let input = match day {
+        Some(_) => input::single_day_arg(input),
+        None if input.is_some() => bail!("an input can only be given when running a single day"),
+        None => None,
+    };
+
+    let selected = select(Some(year), day)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut failures = 0;
+
+    for solution in selected {
+        println!("{} day {}", solution.year(), solution.day());
+
+        let input = Source::resolve(solution.year(), solution.day(), input.as_deref()).read()?;
+
+        match solution.solve(&input, &parts) {
+            Ok(report) => {
+                failures += report.parts.iter().filter(|p| p.answer.is_err()).count();
+
+                for line in report.to_string().lines() {
+                    println!("  {line}");
+                }
+            }
+            Err(e) => {
+                failures += parts.len();
+                println!("  parse: error: {e:#}");
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+
+    Ok(())
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> Result<()> {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for solution in select(year, day)? {
+        println!("{} day {}", solution.year(), solution.day());
+
+        let answers = Answers::load(solution.year(), solution.day())?;
+        let input = Source::resolve(solution.year(), solution.day(), None).read()?;
+
+        let report = match solution.solve(&input, &Part::ALL) {
+            Ok(report) => report,
+            Err(e) => {
+                failed += Part::ALL.len();
+                println!("  parse: error: {e:#}");
+                continue;
+            }
+        };
+
+        for part in report.parts {
+            let verdict = match &part.answer {
+                Ok(answer) => answers.check(part.part, answer),
+                Err(_) => Verdict::Fail {
+                    expected: answers.get(part.part).unwrap_or_else(|| "?".into()),
+                },
+            };
+
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+
+            match &part.answer {
+                Ok(answer) => println!("  part {}: {answer} [{verdict}]", part.part),
+                Err(e) => println!("  part {}: error: {e:#} [{verdict}]", part.part),
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+
+    if failed > 0 {
+        bail!("{failed} part(s) failed verification");
+    }
+
+    Ok(())
+}
+
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: usize,
+    output: PathBuf,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> Result<()> {
+    // Load the baseline first, so that a bad path doesn't waste a whole run
+    let baseline = baseline.map(BenchResults::load).transpose()?;
+    let mut results = BenchResults::default();
+
+    for solution in select(year, day)? {
+        println!("{} day {}", solution.year(), solution.day());
+
+        let input = Source::resolve(solution.year(), solution.day(), None).read()?;
+        let bench = solution.bench(&input, runs)?;
+
+        for (stage, stats) in bench.stages() {
+            println!("  {stage}: {stats}");
+        }
+
+        results.insert(solution.year(), solution.day(), bench);
+    }
+
+    results.save(&output)?;
+    println!("results written to {}", output.display());
+
+    if let Some(baseline) = baseline {
+        let regressions = results.regressions(&baseline, threshold / 100.);
+
+        if !regressions.is_empty() {
+            for regression in &regressions {
+                println!("regression: {regression}");
+            }
+
+            bail!(
+                "{} stage(s) regressed by more than {threshold}%",
+                regressions.len()
+            );
+        }
+
+        println!("no regressions above {threshold}%");
+    }
+
+    Ok(())
+}
+
+fn fetch(year: u16, day: Option<u8>) -> Result<()> {
+    let fetcher = Fetcher::from_env();
+
+    // A whole year only covers the days unlocked so far
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25).filter(|&d| fetch::is_unlocked(year, d)).collect(),
+    };
+
+    for day in days {
+        println!("{year} day {day}: {}", fetcher.fetch(year, day)?);
+    }
+
+    Ok(())
+}
+
+fn readme(runtimes: Option<PathBuf>, check: bool) -> Result<()> {
+    let manifest = workspace_root().join("Cargo.toml");
+    readme::check_members(
+        &std::fs::read_to_string(&manifest)
+            .with_context(|| format!("failed to read {}", manifest.display()))?,
+        solutions::SOLUTIONS,
+    )?;
+
+    let runtimes = runtimes.map(BenchResults::load).transpose()?;
+    let mut stars = BTreeMap::new();
+
+    // Only answers that match the recorded ones earn a star
+    for solution in solutions::SOLUTIONS {
+        let answers = Answers::load(solution.year(), solution.day())?;
+        let input = Source::resolve(solution.year(), solution.day(), None).read()?;
+
+        let earned = match solution.solve(&input, &Part::ALL) {
+            Ok(report) => report
+                .parts
+                .iter()
+                .filter(|p| {
+                    p.answer
+                        .as_ref()
+                        .is_ok_and(|a| answers.check(p.part, a) == Verdict::Pass)
+                })
+                .count(),
+            Err(_) => 0,
+        };
+
+        *stars.entry(solution.year()).or_default() += earned;
+    }
+
+    for (year, stars) in &stars {
+        println!("{year}: {stars}/50");
+    }
+
+    let path = workspace_root().join("../README.md");
+    let current = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let updated = readme::splice(&current, &readme::generate(&stars, runtimes.as_ref()))?;
+
+    if check {
+        ensure!(
+            updated == current,
+            "README.md is out of date, run `aoc readme`"
+        );
+        println!("README.md is up to date");
+    } else {
+        std::fs::write(&path, updated)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        println!("README.md updated");
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let opts = Options::parse();
+
+    match opts.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(
+            year,
+            day,
+            part.map(Part::try_from).transpose()?,
+            input.as_deref(),
+        ),
+        Command::Verify { year, day } => verify(year, day),
+        Command::Bench {
+            year,
+            day,
+            runs,
+            output,
+            baseline,
+            threshold,
+        } => bench(year, day, runs, output, baseline, threshold),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::List => {
+            for solution in solutions::SOLUTIONS {
+                println!("{} day {}", solution.year(), solution.day());
+            }
+
+            Ok(())
+        }
+        Command::Readme { runtimes, check } => readme(runtimes, check),
+        Command::New { year, day } => {
+            scaffold::new_day(workspace_root(), year, day)?;
+            println!("created {year} day {day}");
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use anyhow::{ensure, Context, Result};
+use aoc_utils::{BenchResults, DynSolution};
+
+use crate::scaffold;
+
+// The generated part of the README sits between these markers
+const START: &str = "<!-- progress:start -->";
+const END: &str = "<!-- progress:end -->";
+
+const FIRST_YEAR: u16 = 2015;
+
+fn status(stars: usize) -> &'static str {
+    match stars {
+        0 => "❌",
+        50 => "✅",
+        _ => "🚧",
+    }
+}
+
+// One row per year, from the first event up to the latest year with a solution
+fn status_table(stars: &BTreeMap<u16, usize>) -> String {
+    let last = stars.keys().copied().max().unwrap_or(FIRST_YEAR);
+    let mut table = String::from("| Year | Progress | Status |\n| ---- | -------- | ------ |\n");
+
+    for year in FIRST_YEAR..=last {
+        let stars = stars.get(&year).copied().unwrap_or(0);
+        let progress = format!("{stars}/50");
+
+        // Emoji are two columns wide in most editors
+        table += &format!("| {year} | {progress:<8} | {:<5} |\n", status(stars));
+    }
+
+    table
+}
+
+fn runtimes_table(results: &BenchResults) -> String {
+    let mut table =
+        String::from("| Day | Parse | Part 1 | Part 2 |\n| --- | ----- | ------ | ------ |\n");
+
+    for (key, bench) in &results.0 {
+        let [parse, part1, part2] = bench
+            .stages()
+            .map(|(_, stats)| format!("{:.2?}", Duration::from_nanos(stats.median_ns)));
+
+        table += &format!("| {key} | {parse} | {part1} | {part2} |\n");
+    }
+
+    table
+}
+
+pub fn generate(stars: &BTreeMap<u16, usize>, runtimes: Option<&BenchResults>) -> String {
+    let mut section = status_table(stars);
+
+    if let Some(results) = runtimes {
+        section += "\nMedian runtimes, as measured by `aoc bench`:\n\n";
+        section += &runtimes_table(results);
+    }
+
+    section
+}
+
+pub fn splice(readme: &str, section: &str) -> Result<String> {
+    let start = readme
+        .find(START)
+        .with_context(|| format!("the README has no {START} marker"))?
+        + START.len();
+    let end = readme[start..]
+        .find(END)
+        .map(|i| start + i)
+        .with_context(|| format!("the README has no {END} marker"))?;
+
+    Ok(format!("{}\n{section}{}", &readme[..start], &readme[end..]))
+}
+
+// A day missing from the runner would silently lose its stars
+pub fn check_members(manifest: &str, solutions: &[&dyn DynSolution]) -> Result<()> {
+    let registered = solutions
+        .iter()
+        .map(|s| scaffold::member(s.year(), s.day()))
+        .collect::<Vec<_>>();
+    let missing = scaffold::workspace_members(manifest)?
+        .into_iter()
+        .filter(|m| m.starts_with(|c: char| c.is_ascii_digit()))
+        .filter(|m| !registered.iter().any(|r| r == m))
+        .collect::<Vec<_>>();
+
+    ensure!(
+        missing.is_empty(),
+        "not registered in the runner: {}",
+        missing.join(", ")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn table_covers_every_year() {
+    let stars = BTreeMap::from([(2015, 50), (2016, 6)]);
+    let readme = format!("# AoC\n\n{START}\nstale\n{END}\n");
+
+    assert_eq!(
+        splice(&readme, &generate(&stars, None)).unwrap(),
+        format!(
+            "# AoC\n\n{START}\n\
+             | Year | Progress | Status |\n\
+             | ---- | -------- | ------ |\n\
+             | 2015 | 50/50    | ✅     |\n\
+             | 2016 | 6/50     | 🚧     |\n\
+             {END}\n"
+        )
+    );
+    assert!(splice("# AoC\n", "").is_err());
+}
@@ -0,0 +1,249 @@
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_utils::input;
+
+fn package_name(year: u16, day: u8) -> String {
+    format!("aoc{:02}-day{day:02}", year % 100)
+}
+
+fn crate_name(year: u16, day: u8) -> String {
+    package_name(year, day).replace('-', "_")
+}
+
+pub fn member(year: u16, day: u8) -> String {
+    format!("{year}/day{day:02}")
+}
+
+fn cargo_toml(year: u16, day: u8) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.95"
+aoc_utils = {{ version = "0.1.0", path = "../../aoc_utils" }}
+"#,
+        package_name(year, day)
+    )
+}
+
+fn lib_rs(year: u16, day: u8) -> String {
+    format!(
+        r#"use std::fmt::Display;
+
+use anyhow::{{anyhow, Result}};
+use aoc_utils::Solution;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.to_string())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<impl Display> {{
+        Err::<usize, _>(anyhow!("not solved yet"))
+    }}
+
+    fn part2(_input: &Self::Input) -> Result<impl Display> {{
+        Err::<usize, _>(anyhow!("not solved yet"))
+    }}
+}}
+
+aoc_utils::example_tests!(Day{day:02});
+"#
+    )
+}
+
+fn main_rs(year: u16, day: u8) -> String {
+    format!(
+        "fn main() -> anyhow::Result<()> {{\n    aoc_utils::run::<{}::Day{day:02}>()\n}}\n",
+        crate_name(year, day)
+    )
+}
+
+// Byte range of the contents of the workspace `members` array
+fn members_span(manifest: &str) -> Result<Range<usize>> {
+    let start = manifest
+        .find("members = [")
+        .context("workspace has no members")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .context("unterminated members")?;
+
+    Ok(start..end)
+}
+
+pub fn workspace_members(manifest: &str) -> Result<Vec<&str>> {
+    Ok(manifest[members_span(manifest)?]
+        .split(',')
+        .map(|v| v.trim().trim_matches('"'))
+        .filter(|v| !v.is_empty())
+        .collect())
+}
+
+// Rewrites the `members` array one entry per line, with the days in sorted order
+fn add_workspace_member(manifest: &str, member: &str) -> Result<String> {
+    let span = members_span(manifest)?;
+    let mut members = workspace_members(manifest)?;
+
+    ensure!(!members.contains(&member), "{member} is already a member");
+    members.push(member);
+
+    // Shared crates stay on top, in their original order
+    let (mut days, shared): (Vec<_>, Vec<_>) = members
+        .into_iter()
+        .partition(|m| m.starts_with(|c: char| c.is_ascii_digit()));
+    days.sort();
+
+    let list = shared
+        .into_iter()
+        .chain(days)
+        .map(|m| format!("    \"{m}\",\n"))
+        .collect::<String>();
+
+    Ok(format!(
+        "{}\n{list}{}",
+        &manifest[..span.start],
+        &manifest[span.end..]
+    ))
+}
+
+// Inserts `line` into the block that follows the `header` line, before the first entry that
+// sorts after it. The block ends at the first blank line, section or closing bracket.
+fn insert_sorted_line(text: &str, header: &str, line: &str) -> Result<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    ensure!(
+        !lines.contains(&line),
+        "{} is already registered",
+        line.trim()
+    );
+
+    let first = lines
+        .iter()
+        .position(|l| l.trim_end().ends_with(header))
+        .with_context(|| format!("no {header} to register the day in"))?
+        + 1;
+    let idx = lines[first..]
+        .iter()
+        .position(|l| {
+            let end = l.trim().is_empty() || l.starts_with(['[', ']']);
+            end || *l > line
+        })
+        .map_or(lines.len(), |i| first + i);
+
+    lines.insert(idx, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Computes the new contents of an existing file without writing it yet
+fn edit(path: PathBuf, f: impl FnOnce(&str) -> Result<String>) -> Result<(PathBuf, String)> {
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let text = f(&text).with_context(|| format!("failed to update {}", path.display()))?;
+
+    Ok((path, text))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<()> {
+    let dir = input::day_dir(root, year, day);
+
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    let member = member(year, day);
+
+    // Every registration is prepared up front, so that a failure leaves the tree untouched
+    let edits = [
+        edit(root.join("Cargo.toml"), |text| {
+            add_workspace_member(text, &member)
+        })?,
+        edit(root.join("aoc/Cargo.toml"), |text| {
+            insert_sorted_line(
+                text,
+                "[dependencies]",
+                &format!(
+                    "{} = {{ version = \"0.1.0\", path = \"../{member}\" }}",
+                    package_name(year, day)
+                ),
+            )
+        })?,
+        edit(root.join("aoc/src/solutions.rs"), |text| {
+            insert_sorted_line(
+                text,
+                "= &[",
+                &format!("    &{}::Day{day:02},", crate_name(year, day)),
+            )
+        })?,
+    ];
+
+    std::fs::create_dir_all(dir.join("src"))?;
+    std::fs::create_dir_all(dir.join("examples"))?;
+
+    write(&dir.join("Cargo.toml"), &cargo_toml(year, day))?;
+    write(&dir.join("src/lib.rs"), &lib_rs(year, day))?;
+    write(&dir.join("src/main.rs"), &main_rs(year, day))?;
+    write(&dir.join("answers.toml"), "")?;
+    write(&dir.join("examples/example.txt"), "")?;
+    write(&dir.join("examples/example.toml"), "")?;
+
+    for (path, text) in edits {
+        write(&path, &text)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn members_are_sorted_and_unsquashed() {
+    let manifest =
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"2024/day01\", \"2015/day02\",\n]\n";
+
+    assert_eq!(
+        add_workspace_member(manifest, "2016/day01").unwrap(),
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"2015/day02\",\n    \"2016/day01\",\n    \"2024/day01\",\n]\n"
+    );
+    assert!(add_workspace_member(manifest, "2024/day01").is_err());
+}
+
+#[test]
+fn lines_are_inserted_in_order() {
+    let table = "static X = &[\n    &aoc15_day01::Day01,\n    &aoc24_day01::Day01,\n];\n";
+
+    assert_eq!(
+        insert_sorted_line(table, "= &[", "    &aoc16_day01::Day01,").unwrap(),
+        "static X = &[\n    &aoc15_day01::Day01,\n    &aoc16_day01::Day01,\n    &aoc24_day01::Day01,\n];\n"
+    );
+    assert_eq!(
+        insert_sorted_line(table, "= &[", "    &aoc25_day01::Day01,").unwrap(),
+        "static X = &[\n    &aoc15_day01::Day01,\n    &aoc24_day01::Day01,\n    &aoc25_day01::Day01,\n];\n"
+    );
+
+    let manifest =
+        "[package]\nname = \"aoc\"\n\n[dependencies]\naoc_utils = \"0.1\"\nclap = \"4\"\n";
+
+    assert_eq!(
+        insert_sorted_line(manifest, "[dependencies]", "aoc24-day01 = \"0.1\"").unwrap(),
+        "[package]\nname = \"aoc\"\n\n[dependencies]\naoc24-day01 = \"0.1\"\naoc_utils = \"0.1\"\nclap = \"4\"\n"
+    );
+}
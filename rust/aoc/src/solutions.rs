@@ -0,0 +1,57 @@
+use aoc_utils::DynSolution;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &aoc15_day01::Day01,
+    &aoc15_day02::Day02,
+    &aoc15_day03::Day03,
+    &aoc15_day04::Day04,
+    &aoc15_day05::Day05,
+    &aoc15_day06::Day06,
+    &aoc15_day07::Day07,
+    &aoc15_day08::Day08,
+    &aoc15_day09::Day09,
+    &aoc15_day10::Day10,
+    &aoc15_day11::Day11,
+    &aoc15_day12::Day12,
+    &aoc15_day13::Day13,
+    &aoc15_day14::Day14,
+    &aoc15_day15::Day15,
+    &aoc15_day16::Day16,
+    &aoc15_day17::Day17,
+    &aoc15_day18::Day18,
+    &aoc15_day19::Day19,
+    &aoc15_day20::Day20,
+    &aoc15_day21::Day21,
+    &aoc15_day22::Day22,
+    &aoc15_day23::Day23,
+    &aoc15_day24::Day24,
+    &aoc15_day25::Day25,
+    &aoc16_day01::Day01,
+    &aoc16_day02::Day02,
+    &aoc16_day03::Day03,
+    &aoc24_day01::Day01,
+    &aoc24_day02::Day02,
+    &aoc24_day03::Day03,
+    &aoc24_day04::Day04,
+    &aoc24_day05::Day05,
+    &aoc24_day06::Day06,
+    &aoc24_day07::Day07,
+    &aoc24_day08::Day08,
+    &aoc24_day09::Day09,
+    &aoc24_day10::Day10,
+    &aoc24_day11::Day11,
+    &aoc24_day12::Day12,
+    &aoc24_day13::Day13,
+    &aoc24_day14::Day14,
+    &aoc24_day15::Day15,
+    &aoc24_day16::Day16,
+    &aoc24_day17::Day17,
+    &aoc24_day18::Day18,
+    &aoc24_day19::Day19,
+    &aoc24_day20::Day20,
+    &aoc24_day21::Day21,
+    &aoc24_day22::Day22,
+    &aoc24_day23::Day23,
+    &aoc24_day24::Day24,
+    &aoc24_day25::Day25,
+];
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use aoc_utils::{AStarOptions, IVec2, Map2D};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// Random walls from a fixed seed, with the corners kept open
+fn grid(size: usize, density: u64) -> Map2D<bool> {
+    let mut map = Map2D::new(size, size);
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+    for y in 0..size as i32 {
+        for x in 0..size as i32 {
+            // splitmix64
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+
+            map.set(IVec2::new(x, y), z % 100 < density);
+        }
+    }
+
+    for d in (0..9).map(|i| IVec2::new(i % 3, i / 3)) {
+        map.set(d, false);
+        map.set(IVec2::splat(size as i32 - 1) - d, false);
+    }
+
+    map
+}
+
+// The implementation the binary heap replaced, which re-sorts its whole queue before every pop
+fn sorted_queue_a_star(map: &Map2D<bool>, from: IVec2, to: IVec2) -> Option<usize> {
+    let directions = [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y];
+    let mut queue = vec![from];
+    let mut known = HashMap::from([(from, (0, 0))]);
+
+    while !queue.is_empty() {
+        queue.sort_by(|a, b| known[a].1.cmp(&known[b].1).reverse());
+
+        let pos = queue.pop()?;
+        let steps = known[&pos].0;
+
+        if pos == to {
+            return Some(steps);
+        }
+
+        for d in directions {
+            let next = pos + d;
+
+            if map.get(next) != Some(&false) {
+                continue;
+            }
+
+            if known.get(&next).is_some_and(|&(prev, _)| prev <= steps + 1) {
+                continue;
+            }
+
+            queue.push(next);
+            known.insert(
+                next,
+                (steps + 1, steps + 1 + aoc_utils::manhattan(next, to)),
+            );
+        }
+    }
+
+    None
+}
+
+fn a_star(c: &mut Criterion) {
+    let mut group = c.benchmark_group("a_star");
+    group.sample_size(10);
+
+    for size in [71, 1000] {
+        let map = grid(size, 25);
+        let to = IVec2::splat(size as i32 - 1);
+
+        let steps = map
+            .a_star(AStarOptions::new(IVec2::ZERO, to))
+            .map(|p| p.len() - 1);
+
+        assert!(steps.is_some(), "{size}x{size} should have a path");
+        assert_eq!(steps, sorted_queue_a_star(&map, IVec2::ZERO, to));
+
+        group.bench_with_input(BenchmarkId::new("binary_heap", size), &map, |b, map| {
+            b.iter(|| map.a_star(AStarOptions::new(IVec2::ZERO, to)))
+        });
+        group.bench_with_input(BenchmarkId::new("sorted_queue", size), &map, |b, map| {
+            b.iter(|| sorted_queue_a_star(map, IVec2::ZERO, to))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, a_star);
+criterion_main!(benches);
@@ -0,0 +1,145 @@
+use std::{fmt::Display, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{input, Part};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(v) => write!(f, "{v}"),
+            Answer::Text(v) => f.write_str(v),
+        }
+    }
+}
+
+// Recorded answers of a day, kept in `answers.toml` next to its `input.txt`
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    #[serde(default)]
+    params: Params,
+}
+
+// Puzzle settings that an example scales down, like the size of a grid, from a `[params]` table
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Params(toml::Table);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        let value = self
+            .0
+            .get(key)
+            .with_context(|| format!("missing param {key}"))?;
+
+        value
+            .clone()
+            .try_into()
+            .with_context(|| format!("invalid param {key}"))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        input::day_dir(&input::inputs_dir(), year, day).join("answers.toml")
+    }
+
+    pub fn parse(data: &str) -> Result<Self> {
+        Ok(toml::from_str(data)?)
+    }
+
+    // A missing file is not an error, every answer is just unknown
+    pub fn load(year: u16, day: u8) -> Result<Self> {
+        let path = Self::path(year, day);
+
+        match std::fs::read_to_string(&path) {
+            Ok(data) => Self::parse(&data).with_context(|| format!("in {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub fn get(&self, part: Part) -> Option<String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+        .map(|v| v.to_string())
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[test]
+fn answers_can_be_numbers_or_text() {
+    let answers = Answers::parse("part1 = 1579939\npart2 = \"58,44\"\n").unwrap();
+
+    assert_eq!(answers.check(Part::One, "1579939"), Verdict::Pass);
+    assert_eq!(answers.check(Part::Two, "58,44"), Verdict::Pass);
+    assert_eq!(
+        answers.check(Part::Two, "1,2"),
+        Verdict::Fail {
+            expected: String::from("58,44")
+        }
+    );
+}
+
+#[test]
+fn missing_answers_are_unknown() {
+    let answers = Answers::parse("part1 = 12\n").unwrap();
+
+    assert_eq!(answers.check(Part::Two, "12"), Verdict::Unknown);
+    assert!(Answers::parse("part3 = 12\n").is_err());
+}
+
+#[test]
+fn examples_can_have_params() {
+    let answers = Answers::parse("part1 = 22\n\n[params]\nsize = 7\n").unwrap();
+
+    assert_eq!(answers.params().get::<i32>("size").unwrap(), 7);
+    assert!(answers.params().get::<i32>("fallen").is_err());
+    assert!(answers.params().get::<String>("size").is_err());
+    assert!(Answers::parse("part1 = 22\n").unwrap().params().is_empty());
+}
@@ -0,0 +1,415 @@
+use std::{hash::Hash, iter, str::FromStr};
+
+use anyhow::{bail, Error, Result};
+use glam::IVec2;
+
+use crate::{
+    cycle::{self, Cycle},
+    Map2D,
+};
+
+// Birth and survival neighbour counts of a life-like rule, one bit per count
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    // B3/S23
+    pub const LIFE: Self = Self {
+        birth: 1 << 3,
+        survival: 1 << 2 | 1 << 3,
+    };
+
+    // A cell has at most 8 neighbours, so higher counts are rejected
+    pub fn new(birth: &[u8], survival: &[u8]) -> Result<Self> {
+        let mask = |counts: &[u8]| {
+            counts.iter().try_fold(0, |mask, &n| match n {
+                0..=8 => Ok(mask | 1 << n),
+                _ => bail!("{n} is not a neighbour count"),
+            })
+        };
+
+        Ok(Self {
+            birth: mask(birth)?,
+            survival: mask(survival)?,
+        })
+    }
+
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+
+        neighbours <= 8 && mask >> neighbours & 1 == 1
+    }
+}
+
+// The usual `B3/S23` notation
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((birth, survival)) = s.split_once('/') else {
+            bail!("expected a rule like B3/S23, got {s:?}");
+        };
+
+        let counts = |part: &str, prefix: char| -> Result<Vec<u8>> {
+            let Some(digits) = part.strip_prefix(prefix) else {
+                bail!("{part:?} should start with {prefix}");
+            };
+
+            digits
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(n) => Ok(n as u8),
+                    None => bail!("{c:?} is not a neighbour count"),
+                })
+                .collect()
+        };
+
+        Self::new(&counts(birth, 'B')?, &counts(survival, 'S')?)
+    }
+}
+
+// Runs `rule(grid, pos, cell)` on every cell at once to get the next generation. Pinned cells
+// keep their value whatever the rule says.
+pub struct Automaton<T, F> {
+    grid: Map2D<T>,
+    buffer: Map2D<T>,
+    rule: F,
+    pinned: Vec<(IVec2, T)>,
+    generation: usize,
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone,
+    F: FnMut(&Map2D<T>, IVec2, &T) -> T,
+{
+    pub fn new(grid: Map2D<T>, rule: F) -> Self {
+        Self {
+            buffer: grid.clone(),
+            grid,
+            rule,
+            pinned: vec![],
+            generation: 0,
+        }
+    }
+
+    pub fn pin(&mut self, pos: IVec2, v: T) {
+        self.grid.set(pos, v.clone());
+        self.pinned.push((pos, v));
+    }
+
+    pub fn grid(&self) -> &Map2D<T> {
+        &self.grid
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        for (pos, v) in self.grid.iter_positions() {
+            self.buffer[pos] = (self.rule)(&self.grid, pos, v);
+        }
+
+        std::mem::swap(&mut self.grid, &mut self.buffer);
+
+        for (pos, v) in &self.pinned {
+            self.grid.set(*pos, v.clone());
+        }
+
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    // Steps until a grid comes back, which leaves the automaton at `start + period`
+    pub fn find_cycle(&mut self) -> Cycle
+    where
+        T: Hash + Eq,
+    {
+        let from = self.generation;
+        let grids = iter::once(self.grid.clone()).chain(iter::repeat_with(|| {
+            self.step();
+            self.grid.clone()
+        }));
+        let cycle = cycle::find(grids).expect("generations should go on forever");
+
+        Cycle {
+            start: from + cycle.start,
+            ..cycle
+        }
+    }
+}
+
+// The transition of a life-like rule for `Automaton`, counting the 8 neighbours of every cell
+pub fn life(rule: Rule) -> impl FnMut(&Map2D<bool>, IVec2, &bool) -> bool {
+    move |grid, pos, &alive| {
+        let neighbours = grid.neighbours8(pos).filter(|&(_, _, &v)| v).count();
+
+        rule.next(alive, neighbours)
+    }
+}
+
+// Life-like rules on a grid packed 64 cells to a word, with the neighbour counts of a whole word
+// added up at once as 4 bit planes
+#[derive(Clone, Debug)]
+pub struct BitLife {
+    width: usize,
+    height: usize,
+    words: usize,
+    rule: Rule,
+    cells: Vec<u64>,
+    buffer: Vec<u64>,
+    pin_mask: Vec<u64>,
+    pin_values: Vec<u64>,
+    generation: usize,
+}
+
+impl BitLife {
+    pub fn new(map: &Map2D<bool>, rule: Rule) -> Self {
+        let (width, height) = (map.width(), map.height());
+        let words = width.div_ceil(64);
+        let mut cells = vec![0; words * height];
+
+        for (pos, _) in map.find(|_, &alive| alive) {
+            let (x, y) = (pos.x as usize, pos.y as usize);
+
+            cells[y * words + x / 64] |= 1 << (x % 64);
+        }
+
+        Self {
+            width,
+            height,
+            words,
+            rule,
+            buffer: cells.clone(),
+            pin_mask: vec![0; cells.len()],
+            pin_values: vec![0; cells.len()],
+            cells,
+            generation: 0,
+        }
+    }
+
+    fn bit(&self, pos: IVec2) -> Option<(usize, u64)> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+
+        (x < self.width && y < self.height).then(|| (y * self.words + x / 64, 1 << (x % 64)))
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<bool> {
+        self.bit(pos).map(|(i, bit)| self.cells[i] & bit != 0)
+    }
+
+    pub fn pin(&mut self, pos: IVec2, alive: bool) {
+        let Some((i, bit)) = self.bit(pos) else {
+            return;
+        };
+
+        self.pin_mask[i] |= bit;
+        self.pin_values[i] = (self.pin_values[i] & !bit) | if alive { bit } else { 0 };
+        self.cells[i] = (self.cells[i] & !bit) | (self.pin_values[i] & bit);
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn count(&self) -> usize {
+        self.cells.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn to_map(&self) -> Map2D<bool> {
+        Map2D::from_fn(self.width, self.height, |p| self.get(p) == Some(true))
+    }
+
+    pub fn step(&mut self) {
+        let words = self.words;
+        let empty = vec![0; words];
+        let tail = match self.width % 64 {
+            0 => !0,
+            bits => (1 << bits) - 1,
+        };
+
+        for y in 0..self.height {
+            let row = |y: usize| &self.cells[y * words..(y + 1) * words];
+            let up = if y > 0 { row(y - 1) } else { &empty };
+            let mid = row(y);
+            let down = if y + 1 < self.height {
+                row(y + 1)
+            } else {
+                &empty
+            };
+
+            for j in 0..words {
+                let mut planes = [0u64; 4];
+
+                for (r, centre) in [(up, false), (mid, true), (down, false)] {
+                    // Bit x of `west` is the cell at x - 1, and the other way around for `east`
+                    let west = r[j] << 1 | if j > 0 { r[j - 1] >> 63 } else { 0 };
+                    let east = r[j] >> 1 | r.get(j + 1).map_or(0, |w| w << 63);
+
+                    // A cell isn't its own neighbour
+                    let column = if centre {
+                        [west, east, 0]
+                    } else {
+                        [west, r[j], east]
+                    };
+
+                    for bits in column {
+                        // Ripple-carry addition of one bit into every count at once
+                        let mut carry = bits;
+
+                        for plane in &mut planes {
+                            let next = *plane & carry;
+                            *plane ^= carry;
+                            carry = next;
+                        }
+                    }
+                }
+
+                let equals = |n: usize| {
+                    (0..4).fold(!0, |acc, k| {
+                        acc & if n >> k & 1 == 1 {
+                            planes[k]
+                        } else {
+                            !planes[k]
+                        }
+                    })
+                };
+                let matching = |mask: u16| {
+                    (0..=8)
+                        .filter(|n| mask >> n & 1 == 1)
+                        .fold(0, |acc, n| acc | equals(n))
+                };
+
+                let cell = mid[j];
+                let i = y * words + j;
+                let next =
+                    (!cell & matching(self.rule.birth)) | (cell & matching(self.rule.survival));
+                let next = (next & !self.pin_mask[i]) | self.pin_values[i];
+
+                self.buffer[i] = if j + 1 == words { next & tail } else { next };
+            }
+        }
+
+        std::mem::swap(&mut self.cells, &mut self.buffer);
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    // Steps until a grid comes back, which leaves it at `start + period`
+    pub fn find_cycle(&mut self) -> Cycle {
+        let from = self.generation;
+        let grids = iter::once(self.cells.clone()).chain(iter::repeat_with(|| {
+            self.step();
+            self.cells.clone()
+        }));
+        let cycle = cycle::find(grids).expect("generations should go on forever");
+
+        Cycle {
+            start: from + cycle.start,
+            ..cycle
+        }
+    }
+}
+
+#[test]
+fn rules_parse_from_notation() {
+    assert_eq!("B3/S23".parse::<Rule>().unwrap(), Rule::LIFE);
+    assert_eq!(
+        "B36/S23".parse::<Rule>().unwrap(),
+        Rule::new(&[3, 6], &[2, 3]).unwrap()
+    );
+    assert!(Rule::new(&[3], &[2, 16]).is_err());
+    assert!(!Rule::LIFE.next(true, 19));
+    assert!("B9/S23".parse::<Rule>().is_err());
+    assert!("S23/B3".parse::<Rule>().is_err());
+    assert!(Rule::LIFE.next(false, 3) && !Rule::LIFE.next(false, 2));
+}
+
+#[test]
+fn both_engines_animate_the_lights_example() {
+    let start = Map2D::read_str(
+        ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..\n",
+        |c: char| c == '#',
+    )
+    .unwrap();
+    let corners = [(0, 0), (5, 0), (0, 5), (5, 5)].map(IVec2::from);
+
+    let mut slow = Automaton::new(start.clone(), life(Rule::LIFE));
+    let mut fast = BitLife::new(&start, Rule::LIFE);
+
+    slow.run(4);
+    fast.run(4);
+    assert_eq!(fast.count(), 4);
+    assert_eq!(&fast.to_map(), slow.grid());
+
+    let mut slow = Automaton::new(start.clone(), life(Rule::LIFE));
+    let mut fast = BitLife::new(&start, Rule::LIFE);
+
+    for pos in corners {
+        slow.pin(pos, true);
+        fast.pin(pos, true);
+    }
+
+    slow.run(5);
+    fast.run(5);
+    assert_eq!(fast.count(), 17);
+    assert_eq!(&fast.to_map(), slow.grid());
+}
+
+#[test]
+fn cycles_are_detected() {
+    // A blinker next to a block, on a grid wide enough to span several words
+    let mut map = Map2D::new(130, 5);
+
+    for pos in [(63, 1), (64, 1), (65, 1), (0, 0), (1, 0), (0, 1), (1, 1)] {
+        map.set(IVec2::from(pos), true);
+    }
+
+    let mut fast = BitLife::new(&map, Rule::LIFE);
+    let mut slow = Automaton::new(map, life(Rule::LIFE));
+
+    assert_eq!(
+        fast.find_cycle(),
+        Cycle {
+            start: 0,
+            period: 2
+        }
+    );
+    assert_eq!(
+        slow.find_cycle(),
+        Cycle {
+            start: 0,
+            period: 2
+        }
+    );
+
+    // A counter that settles after a few steps
+    let mut counter = Automaton::new(Map2D::from_fn(3, 1, |p| p.x), |_, _, &v| (v + 1).min(4));
+    assert_eq!(
+        counter.find_cycle(),
+        Cycle {
+            start: 4,
+            period: 1
+        }
+    );
+    assert_eq!(counter.grid().as_ref(), &[4, 4, 4]);
+
+    // Generations stay absolute when the search starts later on
+    let mut counter = Automaton::new(Map2D::from_fn(3, 1, |p| p.x), |_, _, &v| (v + 1).min(4));
+    counter.run(2);
+    assert_eq!(counter.find_cycle().start, 4);
+}
@@ -0,0 +1,219 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "there should be at least one sample");
+
+        samples.sort();
+
+        let ns = |d: Duration| d.as_nanos() as u64;
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (ns(samples[mid - 1]) + ns(samples[mid])) / 2
+        } else {
+            ns(samples[mid])
+        };
+
+        Self {
+            median_ns: median,
+            min_ns: ns(samples[0]),
+            max_ns: ns(samples[samples.len() - 1]),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let d = Duration::from_nanos;
+
+        write!(
+            f,
+            "{:.2?} (min {:.2?}, max {:.2?})",
+            d(self.median_ns),
+            d(self.min_ns),
+            d(self.max_ns)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+fn sample<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<(Stats, T)> {
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let v = f()?;
+        samples.push(start.elapsed());
+        last = Some(v);
+    }
+
+    Ok((Stats::from_samples(&mut samples), last.unwrap()))
+}
+
+pub fn bench<S: Solution>(input: &str, runs: usize) -> Result<DayBench> {
+    ensure!(runs > 0, "there should be at least one run");
+
+    let (parse, input) = sample(runs, || S::parse(input))?;
+    let (part1, _) = sample(runs, || S::part1(&input).map(|v| v.to_string()))?;
+    let (part2, _) = sample(runs, || S::part2(&input).map(|v| v.to_string()))?;
+
+    Ok(DayBench {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+// Results of a benchmark run, keyed by "<year>/<day>"
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResults(pub BTreeMap<String, DayBench>);
+
+impl BenchResults {
+    pub fn key(year: u16, day: u8) -> String {
+        format!("{year}/{day:02}")
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, bench: DayBench) {
+        self.0.insert(Self::key(year, day), bench);
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        serde_json::from_str(&data).with_context(|| format!("in {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    // Stages whose median got slower than the baseline by more than `threshold` (0.1 = 10%)
+    pub fn regressions(&self, baseline: &Self, threshold: f64) -> Vec<Regression> {
+        let mut regressions = vec![];
+
+        for (key, bench) in &self.0 {
+            let Some(previous) = baseline.0.get(key) else {
+                continue;
+            };
+
+            for ((stage, current), (_, previous)) in
+                bench.stages().into_iter().zip(previous.stages())
+            {
+                let ratio = current.median_ns as f64 / previous.median_ns.max(1) as f64;
+
+                if ratio > 1. + threshold {
+                    regressions.push(Regression {
+                        key: key.clone(),
+                        stage,
+                        previous: *previous,
+                        current: *current,
+                    });
+                }
+            }
+        }
+
+        regressions
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub key: String,
+    pub stage: &'static str,
+    pub previous: Stats,
+    pub current: Stats,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}: {:.2?} -> {:.2?} (+{:.0}%)",
+            self.key,
+            self.stage,
+            Duration::from_nanos(self.previous.median_ns),
+            Duration::from_nanos(self.current.median_ns),
+            (self.current.median_ns as f64 / self.previous.median_ns.max(1) as f64 - 1.) * 100.
+        )
+    }
+}
+
+#[test]
+fn stats_are_order_independent() {
+    let mut samples = [5, 1, 3, 2].map(Duration::from_nanos);
+
+    assert_eq!(
+        Stats::from_samples(&mut samples),
+        Stats {
+            median_ns: 2,
+            min_ns: 1,
+            max_ns: 5
+        }
+    );
+}
+
+#[test]
+fn regressions_respect_threshold() {
+    let stats = |median_ns| Stats {
+        median_ns,
+        min_ns: median_ns,
+        max_ns: median_ns,
+    };
+    let day = |parse, part1, part2| DayBench {
+        parse: stats(parse),
+        part1: stats(part1),
+        part2: stats(part2),
+    };
+
+    let mut baseline = BenchResults::default();
+    baseline.insert(2024, 1, day(100, 100, 100));
+
+    let mut current = BenchResults::default();
+    current.insert(2024, 1, day(105, 150, 80));
+    current.insert(2024, 2, day(100, 100, 100));
+
+    let regressions = current.regressions(&baseline, 0.1);
+
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].key, "2024/01");
+    assert_eq!(regressions[0].stage, "part 1");
+}
@@ -0,0 +1,188 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+// A sequence of states that repeats: the state at `start + period` is the one at `start`, and
+// nothing before `start` ever comes back
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // The first step with the same state as step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+// Floyd's tortoise and hare, in constant memory. Also returns the first state of the loop.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    (Cycle { start, period }, tortoise)
+}
+
+// Brent's variant of `floyd`, which usually needs fewer steps
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = step(&hare);
+        period += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    (Cycle { start, period }, tortoise)
+}
+
+// Remembers every state, which are returned in order up to the end of the first loop
+pub fn hashed<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = FxHashMap::default();
+    let mut states = vec![];
+    let mut state = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let period = states.len() - start;
+
+            return (Cycle { start, period }, states);
+        }
+
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(std::mem::replace(&mut state, next));
+    }
+}
+
+// The first repeat in a stream of states, which are moved into a map rather than copied, or `None`
+// if the stream ends before then
+pub fn find<S: Hash + Eq>(states: impl IntoIterator<Item = S>) -> Option<Cycle> {
+    let mut seen = FxHashMap::default();
+
+    for (i, state) in states.into_iter().enumerate() {
+        if let Some(start) = seen.insert(state, i) {
+            return Some(Cycle {
+                start,
+                period: i - start,
+            });
+        }
+    }
+
+    None
+}
+
+// The state after `n` steps, skipping over every full loop once one is found
+pub fn state_at<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = FxHashMap::default();
+    let mut states = vec![];
+    let mut state = initial;
+
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let period = states.len() - start;
+
+            return states.swap_remove(Cycle { start, period }.equivalent_step(n));
+        }
+
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(std::mem::replace(&mut state, next));
+    }
+
+    state
+}
+
+#[cfg(test)]
+fn rho(state: &u32) -> u32 {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    match state {
+        4 => 2,
+        n => n + 1,
+    }
+}
+
+#[test]
+fn detectors_agree() {
+    let expected = Cycle {
+        start: 2,
+        period: 3,
+    };
+
+    assert_eq!(floyd(0, rho), (expected, 2));
+    assert_eq!(brent(0, rho), (expected, 2));
+    assert_eq!(hashed(0, rho), (expected, vec![0, 1, 2, 3, 4]));
+    assert_eq!(
+        find(std::iter::successors(Some(0), |s| Some(rho(s)))),
+        Some(expected)
+    );
+    assert_eq!(find(0..10), None);
+    assert_eq!(brent(7, |_| 7).0.period, 1);
+}
+
+#[test]
+fn far_states_are_extrapolated() {
+    assert_eq!(state_at(0, rho, 1), 1);
+    assert_eq!(state_at(0, rho, 5), 2);
+    assert_eq!(state_at(0, rho, 1_000_000_000_000), 4);
+    assert_eq!(
+        Cycle {
+            start: 2,
+            period: 3
+        }
+        .equivalent_step(7),
+        4
+    );
+}
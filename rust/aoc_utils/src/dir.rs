@@ -0,0 +1,194 @@
+use anyhow::{bail, Error, Result};
+use glam::IVec2;
+
+// Screen orientation: y grows downwards, so `Up` is (0, -1) and turning right goes clockwise
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir4 {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir8 {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+impl Dir4 {
+    // Clockwise, starting from `Right`
+    pub const ALL: [Self; 4] = [Self::Right, Self::Down, Self::Left, Self::Up];
+
+    pub const fn vec(self) -> IVec2 {
+        match self {
+            Self::Right => IVec2::new(1, 0),
+            Self::Down => IVec2::new(0, 1),
+            Self::Left => IVec2::new(-1, 0),
+            Self::Up => IVec2::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+            Self::Up => '^',
+        }
+    }
+
+    // Only the arrows, for inputs where a letter would be a typo rather than a direction
+    pub fn from_arrow(c: char) -> Result<Self> {
+        match Self::ALL.into_iter().find(|d| d.arrow() == c) {
+            Some(dir) => Ok(dir),
+            None => bail!("{c:?} is not an arrow"),
+        }
+    }
+}
+
+// Arrows (`^v<>`), letters (`UDLR`) and compass points (`NSEW`)
+impl TryFrom<char> for Dir4 {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '>' | 'R' | 'E' => Self::Right,
+            'v' | 'D' | 'S' => Self::Down,
+            '<' | 'L' | 'W' => Self::Left,
+            '^' | 'U' | 'N' => Self::Up,
+            _ => bail!("{c:?} is not a direction"),
+        })
+    }
+}
+
+impl From<Dir4> for IVec2 {
+    fn from(dir: Dir4) -> Self {
+        dir.vec()
+    }
+}
+
+impl Dir8 {
+    // Clockwise, starting from `Right`
+    pub const ALL: [Self; 8] = [
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+        Self::Up,
+        Self::UpRight,
+    ];
+
+    pub const fn vec(self) -> IVec2 {
+        match self {
+            Self::Right => IVec2::new(1, 0),
+            Self::DownRight => IVec2::new(1, 1),
+            Self::Down => IVec2::new(0, 1),
+            Self::DownLeft => IVec2::new(-1, 1),
+            Self::Left => IVec2::new(-1, 0),
+            Self::UpLeft => IVec2::new(-1, -1),
+            Self::Up => IVec2::new(0, -1),
+            Self::UpRight => IVec2::new(1, -1),
+        }
+    }
+
+    // By 45 degrees
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl TryFrom<char> for Dir8 {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Dir4::try_from(c).map(Self::from)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir8> for IVec2 {
+    fn from(dir: Dir8) -> Self {
+        dir.vec()
+    }
+}
+
+#[test]
+fn directions_parse_from_glyphs() {
+    let parse = |s: &str| s.chars().map(Dir4::try_from).collect::<Result<Vec<_>, _>>();
+
+    for glyphs in [">v<^", "RDLU", "ESWN"] {
+        assert_eq!(parse(glyphs).unwrap(), Dir4::ALL);
+    }
+
+    assert!(parse("x").is_err());
+    assert_eq!(Dir4::from_arrow('v').unwrap(), Dir4::Down);
+    assert!(Dir4::from_arrow('D').is_err());
+    assert_eq!(Dir8::try_from('N').unwrap(), Dir8::Up);
+    assert_eq!(
+        Dir4::ALL.map(|d| d.arrow()).iter().collect::<String>(),
+        ">v<^"
+    );
+}
+
+#[test]
+fn directions_turn() {
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Left.turn_around(), Dir4::Right);
+    assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+    assert_eq!(Dir8::Right.turn_left(), Dir8::UpRight);
+    assert_eq!(Dir8::DownLeft.turn_around(), Dir8::UpRight);
+
+    for d in Dir4::ALL {
+        // A right turn in screen space is a rotation from x towards y
+        assert_eq!(d.turn_right().vec(), d.vec().perp());
+        assert_eq!(Dir8::from(d).vec(), d.vec());
+        assert_eq!(-d.vec(), d.turn_around().vec());
+    }
+
+    for d in Dir8::ALL {
+        assert_eq!(d.is_diagonal(), d.vec().x != 0 && d.vec().y != 0);
+    }
+}
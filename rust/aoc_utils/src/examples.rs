@@ -0,0 +1,179 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{solve_example, Answers, Part, Solution, Verdict};
+
+// An `examples/<name>.txt` input, with its expected answers in `examples/<name>.toml`
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+// Names of every example in `dir`, which has to exist, sorted
+pub fn example_names(dir: impl AsRef<Path>) -> Result<Vec<String>> {
+    let dir = dir.as_ref();
+    let mut paths = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to list {}", dir.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<PathBuf>>>()?;
+    paths.retain(|p| p.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_stem().context("example without a name")?;
+
+            Ok(name.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+pub fn load_example(dir: impl AsRef<Path>, name: &str) -> Result<Example> {
+    let dir = dir.as_ref();
+    let path = dir.join(name).with_extension("txt");
+    let sidecar = path.with_extension("toml");
+
+    let input = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let answers = std::fs::read_to_string(&sidecar)
+        .with_context(|| format!("example {name} has no {}", sidecar.display()))
+        .and_then(|data| Answers::parse(&data).with_context(|| format!("in {name}.toml")))?;
+
+    Ok(Example {
+        name: name.to_string(),
+        input,
+        answers,
+    })
+}
+
+// Runs one example and reports all mismatches at once, returning how many parts were checked
+pub fn check_example<S: Solution>(example: &Example) -> Result<usize> {
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|&p| example.answers.get(p).is_some())
+        .collect::<Vec<_>>();
+
+    // A freshly scaffolded example has nothing to check yet
+    if parts.is_empty() {
+        return Ok(0);
+    }
+
+    let report = solve_example::<S>(&example.input, example.answers.params(), &parts)
+        .context("parse error")?;
+    let mut failures = vec![];
+
+    for part in &report.parts {
+        match &part.answer {
+            Ok(answer) => {
+                if let Verdict::Fail { expected } = example.answers.check(part.part, answer) {
+                    failures.push(format!(
+                        "part {}: expected {expected}, got {answer}",
+                        part.part
+                    ));
+                }
+            }
+            Err(e) => failures.push(format!("part {}: {e:#}", part.part)),
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!("{}", failures.join(", "));
+    }
+
+    Ok(report.parts.len())
+}
+
+// Runs every example in `dir`, so that adding one is only a matter of dropping in its files.
+// Each one is checked even when others fail, and every failure is reported under its name.
+pub fn check_examples<S: Solution>(dir: impl AsRef<Path>) -> Result<usize> {
+    let dir = dir.as_ref();
+    let names = example_names(dir)?;
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for name in &names {
+        match load_example(dir, name).and_then(|example| check_example::<S>(&example)) {
+            Ok(parts) => checked += parts,
+            Err(e) => failures.push(format!("{name}: {e:#}")),
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} example(s) failed:\n{}",
+            failures.len(),
+            names.len(),
+            failures.join("\n")
+        );
+    }
+
+    Ok(checked)
+}
+
+// Generates a test that checks a day against every file in its `examples` directory
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+            if let Err(e) = $crate::check_examples::<$solution>(dir) {
+                panic!("{e:#}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+struct Length;
+
+#[cfg(test)]
+impl Solution for Length {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().len())
+    }
+
+    fn part1(len: &Self::Input) -> Result<impl std::fmt::Display> {
+        Ok(*len)
+    }
+
+    fn part2(len: &Self::Input) -> Result<impl std::fmt::Display> {
+        Ok(len * 2)
+    }
+}
+
+#[test]
+fn every_example_file_is_checked_by_name() {
+    let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for (name, input, answers) in [
+        ("good", "abc", "part1 = 3\npart2 = 6\n"),
+        ("wrong", "abcd", "part1 = 4\npart2 = 5\n"),
+        ("fresh", "", ""),
+    ] {
+        std::fs::write(dir.join(format!("{name}.txt")), input).unwrap();
+        std::fs::write(dir.join(format!("{name}.toml")), answers).unwrap();
+    }
+
+    let error = format!("{:#}", check_examples::<Length>(&dir).unwrap_err());
+    std::fs::remove_file(dir.join("wrong.txt")).unwrap();
+    let checked = check_examples::<Length>(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        error,
+        "1 of 3 example(s) failed:\nwrong: part 2: expected 5, got 8"
+    );
+    assert_eq!(checked.unwrap(), 2);
+    assert!(check_examples::<Length>(&dir).is_err());
+}
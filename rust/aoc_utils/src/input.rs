@@ -0,0 +1,103 @@
+use std::{
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+pub const INPUT_VAR: &str = "AOC_INPUT";
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    // An explicit input, else the day's file in the inputs directory
+    pub fn resolve(year: u16, day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+            None => Self::File(day_path(&inputs_dir(), year, day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("failed to read stdin")?;
+
+                Ok(input)
+            }
+            Source::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => f.write_str("<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn inputs_dir() -> PathBuf {
+    match std::env::var_os(INPUTS_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+    }
+}
+
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day:02}"))
+}
+
+pub fn day_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    day_dir(root, year, day).join("input.txt")
+}
+
+// The input given to a single day: the argument, then $AOC_INPUT. Runs over several days only take
+// their inputs from the inputs directory, so this must not be used for them.
+pub fn single_day_arg(arg: Option<&str>) -> Option<String> {
+    arg.map(String::from)
+        .or_else(|| std::env::var(INPUT_VAR).ok())
+}
+
+pub fn read(year: u16, day: u8, arg: Option<&str>) -> Result<String> {
+    Source::resolve(year, day, single_day_arg(arg).as_deref()).read()
+}
+
+#[test]
+fn explicit_argument_wins() {
+    assert_eq!(Source::resolve(2024, 1, Some("-")), Source::Stdin);
+    assert_eq!(
+        Source::resolve(2024, 1, Some("example.txt")),
+        Source::File(PathBuf::from("example.txt"))
+    );
+    assert_eq!(single_day_arg(Some("-")).as_deref(), Some("-"));
+}
+
+#[test]
+fn several_days_read_from_the_inputs_directory() {
+    assert_eq!(
+        Source::resolve(2024, 1, None),
+        Source::File(day_path(&inputs_dir(), 2024, 1))
+    );
+}
+
+#[test]
+fn day_paths_are_zero_padded() {
+    assert_eq!(
+        day_path(Path::new("inputs"), 2015, 7),
+        Path::new("inputs/2015/day07/input.txt")
+    );
+}
@@ -1,8 +1,30 @@
+mod answers;
+pub mod automaton;
+mod bench;
+pub mod cycle;
+mod dir;
+mod examples;
+pub mod input;
 mod map;
+pub mod patrol;
+mod rect;
+pub mod search;
+pub mod sokoban;
+mod solution;
+mod sparse;
+mod wrap;
 
+pub use answers::*;
+pub use bench::*;
+pub use dir::*;
+pub use examples::*;
 pub use map::*;
+pub use rect::*;
+pub use solution::*;
+pub use sparse::*;
+pub use wrap::*;
 
-pub use glam::{IVec2, Vec2};
+pub use glam::{I64Vec2, IVec2, Vec2};
 pub use rustc_hash::{FxHashMap, FxHashSet};
 
 pub fn manhattan(a: IVec2, b: IVec2) -> usize {
@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+
+use glam::IVec2;
+
+use super::{Map2D, MOVEMENT_DIRECTIONS};
+
+impl<T> Map2D<T> {
+    // Steps from the nearest source to every cell reachable from one, moving to a neighbour only
+    // when `can_step(from, to)` holds. Unreachable cells are `None`.
+    pub fn bfs(
+        &self,
+        sources: impl IntoIterator<Item = IVec2>,
+        mut can_step: impl FnMut(&T, &T) -> bool,
+    ) -> Map2D<Option<u32>> {
+        let mut dist = Map2D::<Option<u32>>::new(self.width, self.height());
+        let mut queue = VecDeque::new();
+
+        for source in sources {
+            if let Some(i) = self.index_of(source) {
+                if dist.data[i].replace(0).is_none() {
+                    queue.push_back(i);
+                }
+            }
+        }
+
+        while let Some(i) = queue.pop_front() {
+            let steps = dist.data[i].unwrap_or_default();
+            let pos = self.position_of(i);
+
+            for d in MOVEMENT_DIRECTIONS {
+                let Some(j) = self.index_of(pos + d) else {
+                    continue;
+                };
+
+                if dist.data[j].is_some() || !can_step(&self.data[i], &self.data[j]) {
+                    continue;
+                }
+
+                dist.data[j] = Some(steps + 1);
+                queue.push_back(j);
+            }
+        }
+
+        dist
+    }
+
+    // Labels every cell with the index of its region, along with the number of regions.
+    // Neighbours share a region when `same(a, b)` holds, which should be symmetric.
+    pub fn components(&self, mut same: impl FnMut(&T, &T) -> bool) -> (Map2D<u32>, u32) {
+        let mut labels = Map2D {
+            width: self.width,
+            data: vec![u32::MAX; self.data.len()].into_boxed_slice(),
+        };
+        let mut count = 0;
+        let mut stack = vec![];
+
+        for start in 0..self.data.len() {
+            if labels.data[start] != u32::MAX {
+                continue;
+            }
+
+            labels.data[start] = count;
+            stack.push(start);
+
+            while let Some(i) = stack.pop() {
+                let pos = self.position_of(i);
+
+                for d in MOVEMENT_DIRECTIONS {
+                    let Some(j) = self.index_of(pos + d) else {
+                        continue;
+                    };
+
+                    if labels.data[j] == u32::MAX && same(&self.data[i], &self.data[j]) {
+                        labels.data[j] = count;
+                        stack.push(j);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        (labels, count)
+    }
+}
+
+#[test]
+fn bfs_starts_from_every_source() {
+    let map = Map2D::read_str("..#..\n..#..\n.....\n", |c: char| c == '#').unwrap();
+    let dist = map.bfs([IVec2::new(0, 0), IVec2::new(4, 0)], |_, &wall| !wall);
+
+    assert_eq!(dist[IVec2::new(1, 1)], Some(2));
+    assert_eq!(dist[IVec2::new(3, 0)], Some(1));
+    assert_eq!(dist[IVec2::new(2, 2)], Some(4));
+    assert_eq!(dist[IVec2::new(2, 0)], None);
+}
+
+#[test]
+fn components_follow_the_predicate() {
+    let map = Map2D::read_str("AAAA\nBBCD\nBBCC\nEEEC\n", |c: char| c).unwrap();
+
+    let (labels, count) = map.components(|a, b| a == b);
+
+    assert_eq!(count, 5);
+    assert_eq!(labels[IVec2::new(2, 1)], labels[IVec2::new(3, 3)]);
+    assert_ne!(labels[IVec2::new(0, 1)], labels[IVec2::new(0, 3)]);
+
+    // Only telling A apart from everything else
+    let (_, count) = map.components(|&a, &b| (a == 'A') == (b == 'A'));
+
+    assert_eq!(count, 2);
+}
@@ -0,0 +1,461 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::Read,
+    ops::{Index, IndexMut},
+    path::Path,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use glam::IVec2;
+
+use crate::{Dir4, Dir8};
+
+mod fill;
+mod region;
+mod render;
+mod transform;
+
+pub use region::Region;
+pub use render::{Colour, Render};
+pub use transform::View;
+
+static MOVEMENT_DIRECTIONS: &[IVec2] = &[
+    Dir4::Right.vec(),
+    Dir4::Down.vec(),
+    Dir4::Left.vec(),
+    Dir4::Up.vec(),
+];
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Map2D<T> {
+    width: usize,
+    data: Box<[T]>,
+}
+
+pub trait MapConstructParam {
+    fn from(ch: char, x: usize, y: usize) -> Self;
+}
+
+impl MapConstructParam for char {
+    fn from(ch: char, _: usize, _: usize) -> Self {
+        ch
+    }
+}
+
+impl MapConstructParam for (char, IVec2) {
+    fn from(ch: char, x: usize, y: usize) -> Self {
+        (ch, IVec2::new(x as i32, y as i32))
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn read_file<P>(path: impl AsRef<Path>, f: impl FnMut(P) -> T) -> Result<Self>
+    where
+        P: MapConstructParam,
+    {
+        Self::read(File::open(path)?, f)
+    }
+
+    pub fn read_str<P: MapConstructParam>(data: &str, f: impl FnMut(P) -> T) -> Result<Self> {
+        Ok(Self::parse(data, f)?.0)
+    }
+
+    pub fn read<P: MapConstructParam>(
+        mut reader: impl Read,
+        f: impl FnMut(P) -> T,
+    ) -> Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+
+        Self::read_str(&data, f)
+    }
+
+    pub fn parse<P: MapConstructParam>(
+        input: &str,
+        mut f: impl FnMut(P) -> T,
+    ) -> Result<(Self, &str)> {
+        Self::try_parse(input, |p| Ok(f(p)))
+    }
+
+    // Parses the grid at the start of `input`, up to the first blank line, and returns it along
+    // with whatever follows that line. Errors point at the line and column within `input`.
+    pub fn try_parse<P: MapConstructParam>(
+        input: &str,
+        mut f: impl FnMut(P) -> Result<T>,
+    ) -> Result<(Self, &str)> {
+        let mut data = vec![];
+        let mut width = 0;
+        let mut rest = input;
+
+        for y in 0.. {
+            let (line, next) = rest.split_once('\n').unwrap_or((rest, ""));
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            if line.is_empty() {
+                rest = next;
+                break;
+            }
+
+            let mut len = 0;
+
+            for (x, ch) in line.chars().enumerate() {
+                if y > 0 && x == width {
+                    bail!(
+                        "line {}, column {}: row is longer than the first one ({width})",
+                        y + 1,
+                        x + 1
+                    );
+                }
+
+                data.push(
+                    f(P::from(ch, x, y))
+                        .with_context(|| format!("line {}, column {}", y + 1, x + 1))?,
+                );
+                len = x + 1;
+            }
+
+            if y == 0 {
+                width = len;
+            } else if len < width {
+                bail!(
+                    "line {}, column {}: row is shorter than the first one ({width})",
+                    y + 1,
+                    len + 1
+                );
+            }
+
+            rest = next;
+        }
+
+        ensure!(width > 0, "the grid is empty");
+
+        Ok((
+            Self {
+                width,
+                data: data.into_boxed_slice(),
+            },
+            rest,
+        ))
+    }
+
+    pub fn new(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        Self {
+            width,
+            data: (0..(width * height)).map(|_| T::default()).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        // A map without columns has no rows either, like `rows()`
+        if self.width == 0 {
+            return 0;
+        }
+
+        self.data.len() / self.width
+    }
+
+    fn index_of(&self, pos: IVec2) -> Option<usize> {
+        let x: usize = pos.x.try_into().ok()?;
+        let y: usize = pos.y.try_into().ok()?;
+
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        Some(x + y * self.width())
+    }
+
+    fn position_of(&self, i: usize) -> IVec2 {
+        IVec2::new((i % self.width) as i32, (i / self.width) as i32)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.data.get(self.index_of(pos)?)
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        let i = self.index_of(pos)?;
+
+        self.data.get_mut(i)
+    }
+
+    pub fn set(&mut self, IVec2 { x, y }: IVec2, v: T) {
+        let [Ok(x), Ok(y)] = [x, y].map(usize::try_from) else {
+            return;
+        };
+
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
+
+        self.data[x + y * self.width()] = v;
+    }
+
+    // The in-bounds orthogonal neighbours of `pos`, clockwise from the right
+    pub fn neighbours4(&self, pos: IVec2) -> impl Iterator<Item = (Dir4, IVec2, &T)> + '_ {
+        Dir4::ALL.into_iter().filter_map(move |d| {
+            let next = pos + d.vec();
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+
+    // Same as `neighbours4`, diagonals included
+    pub fn neighbours8(&self, pos: IVec2) -> impl Iterator<Item = (Dir8, IVec2, &T)> + '_ {
+        Dir8::ALL.into_iter().filter_map(move |d| {
+            let next = pos + d.vec();
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+
+    pub fn iter_positions(&self) -> impl Iterator<Item = (IVec2, &T)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (self.position_of(i), v))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // An empty map has no rows, rather than a zero chunk size
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.data.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn find<'a>(
+        &'a self,
+        mut f: impl (FnMut(IVec2, &T) -> bool) + 'a,
+    ) -> impl Iterator<Item = (IVec2, &'a T)> + 'a {
+        self.data.iter().enumerate().filter_map(move |(i, v)| {
+            let pos = self.position_of(i);
+
+            if f(pos, v) {
+                Some((pos, v))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+pub struct AStarOptions<'a> {
+    pub from: IVec2,
+    pub to: IVec2,
+    pub directions: &'a [IVec2],
+    pub max_steps: Option<usize>,
+}
+
+impl AStarOptions<'_> {
+    pub fn new(from: IVec2, to: IVec2) -> Self {
+        Self {
+            from,
+            to,
+            directions: MOVEMENT_DIRECTIONS,
+            max_steps: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Visit {
+    steps: u32,
+    prev: Option<IVec2>,
+}
+
+impl Default for Visit {
+    fn default() -> Self {
+        Self {
+            steps: u32::MAX,
+            prev: None,
+        }
+    }
+}
+
+impl Map2D<bool> {
+    // Returns the path from `to` back to `from`, both included
+    pub fn a_star(
+        &'_ self,
+        AStarOptions {
+            from,
+            to,
+            directions,
+            max_steps,
+        }: AStarOptions<'_>,
+    ) -> Option<Vec<IVec2>> {
+        let mut visits = Map2D::<Visit>::new(self.width(), self.height());
+        let mut queue = BinaryHeap::new();
+
+        let start = visits.index_of(from)?;
+        visits.data[start].steps = 0;
+        queue.push(Reverse((crate::manhattan(from, to), 0, from.x, from.y)));
+
+        while let Some(Reverse((_, steps, x, y))) = queue.pop() {
+            let pos = IVec2::new(x, y);
+
+            // A shorter way here was found after this entry was queued
+            if steps > visits[pos].steps {
+                continue;
+            }
+
+            if pos == to {
+                return Some(std::iter::successors(Some(pos), |&p| visits[p].prev).collect());
+            }
+
+            if max_steps.is_some_and(|max| steps as usize >= max) {
+                continue;
+            }
+
+            for d in directions {
+                let next = pos + d;
+
+                if self.get(next) != Some(&false) {
+                    continue;
+                }
+
+                let visit = &mut visits[next];
+
+                if visit.steps <= steps + 1 {
+                    continue;
+                }
+
+                *visit = Visit {
+                    steps: steps + 1,
+                    prev: Some(pos),
+                };
+                queue.push(Reverse((
+                    steps as usize + 1 + crate::manhattan(next, to),
+                    steps + 1,
+                    next.x,
+                    next.y,
+                )));
+            }
+        }
+
+        None
+    }
+}
+
+impl std::fmt::Display for Map2D<bool> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(|&wall| if wall { '#' } else { '.' }).fmt(f)
+    }
+}
+
+impl<T> AsRef<[T]> for Map2D<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> Index<IVec2> for Map2D<T> {
+    type Output = T;
+
+    fn index(&self, index: IVec2) -> &Self::Output {
+        match self.index_of(index) {
+            Some(i) => &self.data[i],
+            None => panic!(
+                "{index} is outside of the {}x{} map",
+                self.width,
+                self.height()
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<IVec2> for Map2D<T> {
+    fn index_mut(&mut self, index: IVec2) -> &mut Self::Output {
+        match self.index_of(index) {
+            Some(i) => &mut self.data[i],
+            None => panic!(
+                "{index} is outside of the {}x{} map",
+                self.width,
+                self.height()
+            ),
+        }
+    }
+}
+
+#[test]
+fn parsing_returns_the_remainder() {
+    let (map, rest) = Map2D::parse("#.\n.#\n\n<>\n", |c: char| c == '#').unwrap();
+
+    assert_eq!((map.width(), map.height()), (2, 2));
+    assert_eq!(map.as_ref(), &[true, false, false, true]);
+    assert_eq!(rest, "<>\n");
+
+    let (_, rest) = Map2D::parse("ab\r\ncd", |c: char| c).unwrap();
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn parse_errors_have_positions() {
+    let err = |input| {
+        Map2D::<char>::try_parse(input, |c: char| match c {
+            '?' => bail!("unexpected {c}"),
+            c => Ok(c),
+        })
+        .unwrap_err()
+        .to_string()
+    };
+
+    assert_eq!(
+        err("ab\nabc\n"),
+        "line 2, column 3: row is longer than the first one (2)"
+    );
+    assert_eq!(
+        err("ab\nab\na\n"),
+        "line 3, column 2: row is shorter than the first one (2)"
+    );
+    assert_eq!(err("ab\na?\n"), "line 2, column 2");
+    assert_eq!(err("\nab"), "the grid is empty");
+}
+
+#[test]
+fn neighbourhoods_stay_in_bounds() {
+    let map = Map2D::read_str("abc\ndef\n", |c: char| c).unwrap();
+
+    let corner = map.neighbours4(IVec2::ZERO).collect::<Vec<_>>();
+    let middle = map.neighbours8(IVec2::new(1, 0)).map(|(_, _, &c)| c);
+
+    assert_eq!(
+        corner,
+        [(Dir4::Right, IVec2::X, &'b'), (Dir4::Down, IVec2::Y, &'d')]
+    );
+    assert_eq!(middle.collect::<String>(), "cfeda");
+}
+
+#[test]
+fn cells_can_be_iterated_and_changed() {
+    let mut map = Map2D::read_str("ab\ncd\n", |c: char| c).unwrap();
+
+    map[IVec2::ZERO] = 'x';
+    *map.get_mut(IVec2::new(1, 1)).unwrap() = 'y';
+    assert!(map.get_mut(IVec2::new(2, 0)).is_none());
+
+    let columns = map.columns().map(|c| c.collect::<String>());
+
+    assert_eq!(map.rows().collect::<Vec<_>>(), [&['x', 'b'], &['c', 'y']]);
+    assert_eq!(columns.collect::<Vec<_>>(), ["xc", "by"]);
+    assert_eq!(map.iter_positions().nth(2), Some((IVec2::new(0, 1), &'c')));
+}
+
+#[test]
+#[should_panic(expected = "outside of the 2x2 map")]
+fn indexing_past_the_width_panics() {
+    let mut map = Map2D::read_str("ab\ncd\n", |c: char| c).unwrap();
+
+    // Would be (0, 1) if the index wrapped onto the next row
+    map[IVec2::new(2, 0)] = 'x';
+}
@@ -0,0 +1,139 @@
+use glam::IVec2;
+
+use super::{Map2D, MOVEMENT_DIRECTIONS};
+use crate::Dir4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub label: u32,
+    pub area: usize,
+    pub perimeter: usize,
+    // Straight runs of the perimeter, which match its number of corners
+    pub sides: usize,
+    // Bounding box, both corners included
+    pub min: IVec2,
+    pub max: IVec2,
+    // Groups of other cells enclosed by the region, with the same 4-neighbourhood as the region
+    pub holes: usize,
+}
+
+impl<T> Map2D<T> {
+    // Labels the regions like `components` does, and measures each of them
+    pub fn regions(&self, same: impl FnMut(&T, &T) -> bool) -> (Map2D<u32>, Vec<Region>) {
+        let (labels, count) = self.components(same);
+        let mut regions = (0..count)
+            .map(|label| Region {
+                label,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: IVec2::MAX,
+                max: IVec2::MIN,
+                holes: 0,
+            })
+            .collect::<Vec<_>>();
+
+        for (i, &label) in labels.data.iter().enumerate() {
+            let pos = labels.position_of(i);
+            let inside = |d: IVec2| labels.get(pos + d) == Some(&label);
+            let region = &mut regions[label as usize];
+
+            region.area += 1;
+            region.min = region.min.min(pos);
+            region.max = region.max.max(pos);
+            region.perimeter += MOVEMENT_DIRECTIONS.iter().filter(|&&d| !inside(d)).count();
+
+            // Every corner of the cell is checked along with the two neighbours sharing it
+            for (a, b) in Dir4::ALL.map(|d| (d.vec(), d.turn_right().vec())) {
+                let convex = !inside(a) && !inside(b);
+                let concave = inside(a) && inside(b) && !inside(a + b);
+
+                if convex || concave {
+                    region.sides += 1;
+                }
+            }
+        }
+
+        for region in &mut regions {
+            region.holes = count_holes(&labels, region);
+        }
+
+        (labels, regions)
+    }
+}
+
+fn count_holes(labels: &Map2D<u32>, region: &Region) -> usize {
+    // The bounding box with a margin, so that all of the outside is a single group
+    let origin = region.min - 1;
+    let size = region.max - region.min + 3;
+    let local = Map2D {
+        width: size.x as usize,
+        data: (0..size.x * size.y)
+            .map(|i| labels.get(origin + IVec2::new(i % size.x, i / size.x)) == Some(&region.label))
+            .collect(),
+    };
+
+    let (groups, _) = local.components(|a, b| a == b);
+    let mut outside = local
+        .find(|_, &inside| !inside)
+        .map(|(pos, _)| groups[pos])
+        .collect::<Vec<_>>();
+    outside.sort_unstable();
+    outside.dedup();
+
+    outside.len() - 1
+}
+
+#[cfg(test)]
+fn fence_prices(garden: &str) -> (usize, usize) {
+    let map = Map2D::read_str(garden, |c: char| c).unwrap();
+
+    map.regions(|a, b| a == b)
+        .1
+        .iter()
+        .fold((0, 0), |(full, discounted), r| {
+            (full + r.area * r.perimeter, discounted + r.area * r.sides)
+        })
+}
+
+#[test]
+fn regions_price_the_garden_examples() {
+    assert_eq!(fence_prices("AAAA\nBBCD\nBBCC\nEEEC\n"), (140, 80));
+    assert_eq!(
+        fence_prices("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n"),
+        (772, 436)
+    );
+    assert_eq!(fence_prices("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n").1, 236);
+    assert_eq!(
+        fence_prices("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n").1,
+        368
+    );
+    assert_eq!(
+        fence_prices(
+            "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\n\
+             VVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE\n"
+        ),
+        (1930, 1206)
+    );
+}
+
+#[test]
+fn regions_know_their_bounds_and_holes() {
+    let map = Map2D::read_str(
+        "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n",
+        |c: char| c,
+    )
+    .unwrap();
+    let (labels, regions) = map.regions(|a, b| a == b);
+
+    let a = &regions[labels[IVec2::ZERO] as usize];
+    let b = &regions[labels[IVec2::new(1, 3)] as usize];
+
+    assert_eq!(regions.len(), 3);
+    assert_eq!((a.min, a.max, a.holes), (IVec2::ZERO, IVec2::splat(5), 2));
+    assert_eq!(
+        (b.min, b.max, b.holes),
+        (IVec2::new(1, 3), IVec2::new(2, 4), 0)
+    );
+    assert_eq!((b.area, b.perimeter, b.sides), (4, 8, 4));
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display, Write};
+
+use glam::IVec2;
+
+use super::Map2D;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        31 + self as u8
+    }
+}
+
+struct Layer {
+    cells: Vec<(IVec2, char)>,
+    colour: Option<Colour>,
+}
+
+// A grid of glyphs with overlays drawn on top in the order they were added. Overlay cells outside
+// of the grid are ignored.
+pub struct Render {
+    glyphs: Map2D<char>,
+    // Position of the top left glyph in the coordinates used by overlays
+    origin: IVec2,
+    layers: Vec<Layer>,
+    ansi: bool,
+}
+
+impl<T> Map2D<T> {
+    pub fn render(&self, glyph: impl Fn(&T) -> char) -> Render {
+        Render::new(self.map(glyph), IVec2::ZERO)
+    }
+}
+
+impl Render {
+    pub fn new(glyphs: Map2D<char>, origin: IVec2) -> Self {
+        Self {
+            glyphs,
+            origin,
+            layers: vec![],
+            ansi: false,
+        }
+    }
+
+    pub fn overlay(
+        mut self,
+        cells: impl IntoIterator<Item = (IVec2, char)>,
+        colour: Option<Colour>,
+    ) -> Self {
+        self.layers.push(Layer {
+            cells: cells.into_iter().collect(),
+            colour,
+        });
+
+        self
+    }
+
+    // Marks every position of a path, e.g. one returned by `a_star`
+    pub fn path(
+        self,
+        path: impl IntoIterator<Item = IVec2>,
+        glyph: char,
+        colour: Option<Colour>,
+    ) -> Self {
+        self.overlay(path.into_iter().map(|pos| (pos, glyph)), colour)
+    }
+
+    // Colours are only written out as ANSI escapes when enabled
+    pub fn with_colour(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+
+        self
+    }
+}
+
+impl Display for Render {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cells = self.glyphs.map(|&glyph| (glyph, None));
+
+        for layer in &self.layers {
+            for &(pos, glyph) in &layer.cells {
+                if let Some(cell) = cells.get_mut(pos - self.origin) {
+                    *cell = (glyph, layer.colour);
+                }
+            }
+        }
+
+        for (y, row) in cells.rows().enumerate() {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+
+            let mut current = None;
+
+            for &(glyph, colour) in row {
+                let colour = colour.filter(|_| self.ansi);
+
+                if colour != current {
+                    match colour {
+                        Some(c) => write!(f, "\x1b[{}m", c.ansi_code())?,
+                        None => f.write_str("\x1b[0m")?,
+                    }
+
+                    current = colour;
+                }
+
+                f.write_char(glyph)?;
+            }
+
+            if current.is_some() {
+                f.write_str("\x1b[0m")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn overlays_are_drawn_in_order() {
+    let map = Map2D::read_str("#..\n.#.\n", |c: char| c == '#').unwrap();
+    let render = map
+        .render(|&wall| if wall { '#' } else { ' ' })
+        .path([IVec2::ZERO, IVec2::X, IVec2::new(2, 0)], 'o', None)
+        .overlay([(IVec2::X, '@'), (IVec2::new(5, 5), '!')], None);
+
+    assert_eq!(render.to_string(), "o@o\n # ");
+    assert_eq!(map.to_string(), "#..\n.#.");
+}
+
+#[test]
+fn colours_are_optional() {
+    let map = Map2D::read_str("#..\n.#.\n", |c: char| c == '#').unwrap();
+    let render = |ansi| {
+        map.render(|&wall| if wall { '#' } else { '.' })
+            .overlay(
+                [(IVec2::X, '@'), (IVec2::new(2, 0), '@')],
+                Some(Colour::Red),
+            )
+            .with_colour(ansi)
+            .to_string()
+    };
+
+    assert_eq!(render(false), "#@@\n.#.");
+    assert_eq!(render(true), "#\x1b[31m@@\x1b[0m\n.#.");
+}
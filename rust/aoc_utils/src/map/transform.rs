@@ -0,0 +1,247 @@
+use std::ops::Index;
+
+use glam::IVec2;
+
+use super::Map2D;
+
+impl<T> Map2D<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(IVec2) -> T) -> Self {
+        Self {
+            width,
+            data: (0..width * height)
+                .map(|i| f(IVec2::new((i % width) as i32, (i / width) as i32)))
+                .collect(),
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Map2D<U> {
+        Map2D {
+            width: self.width,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height(), self.width, |p| {
+            self[IVec2::new(p.y, p.x)].clone()
+        })
+    }
+
+    // Clockwise, as the grid is printed
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height() as i32;
+
+        Self::from_fn(self.height(), self.width, |p| {
+            self[IVec2::new(p.y, h - 1 - p.x)].clone()
+        })
+    }
+
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width as i32;
+
+        Self::from_fn(self.height(), self.width, |p| {
+            self[IVec2::new(w - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self
+    where
+        T: Clone,
+    {
+        let max = IVec2::new(self.width as i32, self.height() as i32) - 1;
+
+        Self::from_fn(self.width, self.height(), |p| self[max - p].clone())
+    }
+
+    // Mirrors left and right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width as i32;
+
+        Self::from_fn(self.width, self.height(), |p| {
+            self[IVec2::new(w - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    // Mirrors top and bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height() as i32;
+
+        Self::from_fn(self.width, self.height(), |p| {
+            self[IVec2::new(p.x, h - 1 - p.y)].clone()
+        })
+    }
+
+    // Every cell becomes a block of `x` by `y` copies of itself
+    pub fn scale(&self, x: usize, y: usize) -> Self
+    where
+        T: Clone,
+    {
+        let factor = IVec2::new(x as i32, y as i32);
+
+        Self::from_fn(self.width * x, self.height() * y, |p| {
+            self[p / factor].clone()
+        })
+    }
+
+    // The `width` by `height` block starting at `origin`, clipped to the grid
+    pub fn view(&self, origin: IVec2, width: usize, height: usize) -> View<'_, T> {
+        let size = IVec2::new(self.width as i32, self.height() as i32);
+        let min = origin.clamp(IVec2::ZERO, size);
+        let max = (origin + IVec2::new(width as i32, height as i32)).clamp(min, size);
+
+        View {
+            map: self,
+            origin: min,
+            width: (max.x - min.x) as usize,
+            height: (max.y - min.y) as usize,
+        }
+    }
+}
+
+// A borrowed rectangle of a `Map2D`, addressed from its own top left corner
+#[derive(Debug)]
+pub struct View<'a, T> {
+    map: &'a Map2D<T>,
+    origin: IVec2,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Where the view's top left corner is in the underlying grid
+    pub fn origin(&self) -> IVec2 {
+        self.origin
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&'a T> {
+        if pos.x < 0 || pos.y < 0 || pos.x >= self.width as i32 || pos.y >= self.height as i32 {
+            return None;
+        }
+
+        self.map.get(self.origin + pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let (map, origin, width) = (self.map, self.origin, self.width);
+
+        (0..self.height).map(move |y| {
+            let start = origin.x as usize + (origin.y as usize + y) * map.width;
+
+            &map.data[start..start + width]
+        })
+    }
+
+    pub fn iter_positions(&self) -> impl Iterator<Item = (IVec2, &'a T)> + 'a {
+        let view = *self;
+
+        (0..self.height as i32)
+            .flat_map(move |y| (0..view.width as i32).map(move |x| IVec2::new(x, y)))
+            .map(move |p| (p, &view.map[view.origin + p]))
+    }
+
+    pub fn to_map(&self) -> Map2D<T>
+    where
+        T: Clone,
+    {
+        Map2D::from_fn(self.width, self.height, |p| self[p].clone())
+    }
+}
+
+impl<T> Index<IVec2> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: IVec2) -> &Self::Output {
+        self.get(index).expect("position outside of the view")
+    }
+}
+
+#[test]
+fn transforms_move_cells_around() {
+    let map = Map2D::read_str("abc\ndef\n", |c: char| c).unwrap();
+    let text = |m: Map2D<char>| {
+        m.rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(text(map.transpose()), ["ad", "be", "cf"]);
+    assert_eq!(text(map.rotate_right()), ["da", "eb", "fc"]);
+    assert_eq!(text(map.rotate_left()), ["cf", "be", "ad"]);
+    assert_eq!(text(map.rotate_180()), ["fed", "cba"]);
+    assert_eq!(text(map.flip_horizontal()), ["cba", "fed"]);
+    assert_eq!(text(map.flip_vertical()), ["def", "abc"]);
+    assert_eq!(text(map.scale(2, 1)), ["aabbcc", "ddeeff"]);
+    assert_eq!(text(map.map(|c| c.to_ascii_uppercase())), ["ABC", "DEF"]);
+    assert_eq!(map.rotate_right().rotate_left(), map);
+}
+
+#[test]
+fn views_are_clipped_sub_grids() {
+    let map = Map2D::read_str("abcd\nefgh\nijkl\n", |c: char| c).unwrap();
+    let view = map.view(IVec2::new(1, 1), 2, 5);
+
+    assert_eq!((view.width(), view.height()), (2, 2));
+    assert_eq!(view[IVec2::ZERO], 'f');
+    assert_eq!(view.get(IVec2::new(2, 0)), None);
+    assert_eq!(view.rows().collect::<Vec<_>>(), [&['f', 'g'], &['j', 'k']]);
+    assert_eq!(
+        view.to_map(),
+        Map2D::read_str("fg\njk\n", |c: char| c).unwrap()
+    );
+
+    let clipped = map.view(IVec2::new(-2, 2), 3, 1);
+    assert_eq!(clipped.origin(), IVec2::new(0, 2));
+    assert_eq!(
+        clipped.iter_positions().map(|(_, c)| c).collect::<String>(),
+        "i"
+    );
+}
+
+#[test]
+fn views_clipped_to_nothing_are_empty_maps() {
+    let map = Map2D::read_str("ab\ncd\n", |c: char| c).unwrap();
+    let empty = map.view(IVec2::new(5, -3), 2, 2).to_map();
+
+    assert_eq!((empty.width(), empty.height()), (0, 0));
+    assert_eq!(empty.rows().count(), 0);
+    assert_eq!(
+        empty
+            .bfs([IVec2::ZERO], |_, _| true)
+            .iter_positions()
+            .count(),
+        0
+    );
+    assert_eq!(Map2D::<u8>::new(0, 3).height(), 0);
+    assert_eq!(map.scale(0, 2).height(), 0);
+}
@@ -0,0 +1,232 @@
+use glam::IVec2;
+use rustc_hash::FxHashSet;
+
+use crate::{Dir4, Map2D};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    // Every state of the guard in order, a turn being a state of its own
+    pub steps: Vec<(IVec2, Dir4)>,
+    // Whether the guard ends up walking in circles rather than leaving the map
+    pub looped: bool,
+}
+
+impl Trace {
+    // Each position visited, once
+    pub fn positions(&self) -> FxHashSet<IVec2> {
+        self.steps.iter().map(|&(pos, _)| pos).collect()
+    }
+}
+
+// A guard walking straight ahead and turning right in front of every obstacle
+#[derive(Clone, Debug)]
+pub struct Patrol {
+    obstacles: Map2D<bool>,
+    // Per direction, where the guard stops in front of the next obstacle, or `None` if it leaves
+    stops: [Map2D<Option<IVec2>>; 4],
+}
+
+impl Patrol {
+    pub fn new(obstacles: Map2D<bool>) -> Self {
+        let stops = Dir4::ALL.map(|dir| {
+            let mut stops = obstacles.map(|_| None);
+            let mut order = obstacles
+                .iter_positions()
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>();
+
+            // Cells further along `dir` first, so the stop ahead is always known already
+            order.sort_unstable_by_key(|p| -p.dot(dir.vec()));
+
+            for pos in order {
+                let next = pos + dir.vec();
+
+                stops[pos] = match obstacles.get(next) {
+                    Some(true) => Some(pos),
+                    Some(false) => stops[next],
+                    None => None,
+                };
+            }
+
+            stops
+        });
+
+        Self { obstacles, stops }
+    }
+
+    pub fn obstacles(&self) -> &Map2D<bool> {
+        &self.obstacles
+    }
+
+    // Empty when the guard starts outside of the map
+    pub fn trace(&self, start: IVec2, dir: Dir4) -> Trace {
+        let mut seen = self.obstacles.map(|_| 0u8);
+        let (mut pos, mut dir) = (start, dir);
+        let mut steps = vec![];
+
+        if self.obstacles.get(start).is_none() {
+            return Trace {
+                steps,
+                looped: false,
+            };
+        }
+
+        loop {
+            let bit = 1 << dir as u8;
+
+            if seen[pos] & bit != 0 {
+                return Trace {
+                    steps,
+                    looped: true,
+                };
+            }
+
+            seen[pos] |= bit;
+            steps.push((pos, dir));
+
+            match self.obstacles.get(pos + dir.vec()) {
+                Some(true) => dir = dir.turn_right(),
+                Some(false) => pos += dir.vec(),
+                None => {
+                    return Trace {
+                        steps,
+                        looped: false,
+                    }
+                }
+            }
+        }
+    }
+
+    // Jumps from one turn to the next, with an extra obstacle that may cut a stretch short. A guard
+    // starting outside of the map never loops, and an obstacle outside of it changes nothing.
+    pub fn loops_with_obstacle(&self, start: IVec2, dir: Dir4, obstacle: IVec2) -> bool {
+        let mut turns = FxHashSet::default();
+        let (mut pos, mut dir) = (start, dir);
+        let placed = self.obstacles.get(obstacle).is_some();
+
+        if self.obstacles.get(start).is_none() {
+            return false;
+        }
+
+        loop {
+            let stop = self.stops[dir as usize][pos];
+
+            // How far along `dir` the obstacle is, if it's straight ahead
+            let ahead = (obstacle - pos).dot(dir.vec());
+            let in_line = placed && ahead > 0 && pos + dir.vec() * ahead == obstacle;
+            let stop = match stop {
+                _ if in_line && stop.is_none_or(|s| ahead <= (s - pos).dot(dir.vec())) => {
+                    obstacle - dir.vec()
+                }
+                Some(s) => s,
+                None => return false,
+            };
+
+            if !turns.insert((stop, dir)) {
+                return true;
+            }
+
+            pos = stop;
+            dir = dir.turn_right();
+        }
+    }
+
+    // Every free cell where one more obstacle sends the guard in circles. Only cells on the
+    // guard's path matter, and each check starts from where the guard first walks into that cell.
+    pub fn loop_obstacles(&self, start: IVec2, dir: Dir4) -> Vec<IVec2> {
+        let trace = self.trace(start, dir);
+        let mut tried = self.obstacles.map(|_| false);
+        let mut found = vec![];
+
+        // The trace is empty, so nothing is tried, when the guard starts outside of the map
+        if let Some(start) = tried.get_mut(start) {
+            *start = true;
+        }
+
+        for pair in trace.steps.windows(2) {
+            let [(from, dir), (to, _)] = [pair[0], pair[1]];
+
+            if from == to || std::mem::replace(&mut tried[to], true) {
+                continue;
+            }
+
+            if self.loops_with_obstacle(from, dir, to) {
+                found.push(to);
+            }
+        }
+
+        found
+    }
+}
+
+#[test]
+fn guards_walk_until_they_leave() {
+    let obstacles = Map2D::read_str(
+        "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+         ..........\n.#..^.....\n........#.\n#.........\n......#...\n",
+        |c: char| c == '#',
+    )
+    .unwrap();
+    let (patrol, start) = (Patrol::new(obstacles), IVec2::new(4, 6));
+    let trace = patrol.trace(start, Dir4::Up);
+
+    assert!(!trace.looped);
+    assert_eq!(trace.positions().len(), 41);
+    assert_eq!(trace.steps[0], (start, Dir4::Up));
+    assert_eq!(trace.steps.last(), Some(&(IVec2::new(7, 9), Dir4::Down)));
+
+    let obstacles = Map2D::read_str(".#..\n...#\n#^..\n..#.\n", |c: char| c == '#').unwrap();
+    assert!(
+        Patrol::new(obstacles)
+            .trace(IVec2::new(1, 2), Dir4::Up)
+            .looped
+    );
+
+    // Starting off the map, even right past the end of a row, goes nowhere
+    let patrol = Patrol::new(Map2D::new(2, 2));
+
+    for start in [IVec2::new(-1, 0), IVec2::new(2, 0)] {
+        assert!(patrol.trace(start, Dir4::Up).steps.is_empty());
+        assert!(patrol.loop_obstacles(start, Dir4::Up).is_empty());
+        assert!(!patrol.loops_with_obstacle(start, Dir4::Up, IVec2::ZERO));
+    }
+}
+
+#[test]
+fn obstacles_that_cause_loops_are_found() {
+    let obstacles = Map2D::read_str(
+        "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+         ..........\n.#..^.....\n........#.\n#.........\n......#...\n",
+        |c: char| c == '#',
+    )
+    .unwrap();
+    let (patrol, start) = (Patrol::new(obstacles), IVec2::new(4, 6));
+    let mut found = patrol.loop_obstacles(start, Dir4::Up);
+    found.sort_by_key(|p| (p.y, p.x));
+
+    let expected = [(3, 6), (6, 7), (7, 7), (1, 8), (3, 8), (7, 9)].map(IVec2::from);
+
+    assert_eq!(found, expected);
+
+    // Right below where the guard leaves, which would stop it if it counted
+    assert!(!patrol.loops_with_obstacle(start, Dir4::Up, IVec2::new(7, 10)));
+
+    // The jumps agree with walking the guard with the obstacle in place
+    for pos in patrol.trace(start, Dir4::Up).positions() {
+        if pos == start {
+            continue;
+        }
+
+        let mut obstacles = patrol.obstacles().clone();
+        obstacles[pos] = true;
+
+        assert_eq!(
+            Patrol::new(obstacles).trace(start, Dir4::Up).looped,
+            expected.contains(&pos)
+        );
+        assert_eq!(
+            patrol.loops_with_obstacle(start, Dir4::Up, pos),
+            expected.contains(&pos)
+        );
+    }
+}
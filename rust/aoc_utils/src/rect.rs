@@ -0,0 +1,187 @@
+use glam::{I64Vec2, IVec2};
+
+use crate::Map2D;
+
+// Both corners included
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Rect {
+    pub min: I64Vec2,
+    pub max: I64Vec2,
+}
+
+impl Rect {
+    pub fn new(a: I64Vec2, b: I64Vec2) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    pub fn area(&self) -> u64 {
+        let size = self.max - self.min + 1;
+
+        size.x as u64 * size.y as u64
+    }
+}
+
+// A grid over the whole i64 plane, cut along the edges of rectangles so that every cell is a block
+// of positions that always share their value. Applying a rectangle visits each block it covers
+// once, whatever their area, but there can be up to the square of the number of rectangles of
+// them. Rectangles not given to `new` cut the grid further, which rebuilds all of the blocks.
+#[derive(Clone, Debug)]
+pub struct RectGrid<T> {
+    // Sorted, each cell starts at one of these and ends right before the next
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    cells: Map2D<T>,
+}
+
+impl<T: Default> RectGrid<T> {
+    pub fn new(rects: impl IntoIterator<Item = Rect>) -> Self {
+        let (mut xs, mut ys): (Vec<_>, Vec<_>) = rects
+            .into_iter()
+            .flat_map(|r| [(r.min.x, r.min.y), (r.max.x + 1, r.max.y + 1)])
+            .unzip();
+
+        for edges in [&mut xs, &mut ys] {
+            edges.sort_unstable();
+            edges.dedup();
+        }
+
+        let cells = Map2D::new(xs.len().saturating_sub(1), ys.len().saturating_sub(1));
+
+        Self { xs, ys, cells }
+    }
+}
+
+impl<T: Clone + Default> RectGrid<T> {
+    // Adds the edges of `rect` to the cuts, a block that gets cut keeping its value on both sides
+    fn cut(&mut self, rect: Rect) {
+        let mut xs = self.xs.clone();
+        let mut ys = self.ys.clone();
+
+        for (edges, from, to) in [
+            (&mut xs, rect.min.x, rect.max.x),
+            (&mut ys, rect.min.y, rect.max.y),
+        ] {
+            for edge in [from, to + 1] {
+                if let Err(i) = edges.binary_search(&edge) {
+                    edges.insert(i, edge);
+                }
+            }
+        }
+
+        if (xs.len(), ys.len()) == (self.xs.len(), self.ys.len()) {
+            return;
+        }
+
+        // The old block a new one is part of, if any
+        let block = |edges: &[i64], start: i64| {
+            let i = edges.partition_point(|&e| e <= start);
+
+            (i > 0 && i < edges.len()).then(|| i as i32 - 1)
+        };
+
+        let cells = Map2D::from_fn(xs.len() - 1, ys.len() - 1, |p| {
+            let x = block(&self.xs, xs[p.x as usize]);
+            let y = block(&self.ys, ys[p.y as usize]);
+
+            match x.zip(y) {
+                Some((x, y)) => self.cells[IVec2::new(x, y)].clone(),
+                None => T::default(),
+            }
+        });
+
+        *self = Self { xs, ys, cells };
+    }
+
+    // Calls `f` on every block of `rect`, cutting the grid along its edges first if needed
+    pub fn apply(&mut self, rect: Rect, mut f: impl FnMut(&mut T)) {
+        self.cut(rect);
+
+        let span = |edges: &[i64], from: i64, to: i64| {
+            let index = |edge| edges.partition_point(|&e| e < edge) as i32;
+
+            index(from)..index(to + 1)
+        };
+        let xs = span(&self.xs, rect.min.x, rect.max.x);
+
+        for y in span(&self.ys, rect.min.y, rect.max.y) {
+            for x in xs.clone() {
+                f(&mut self.cells[IVec2::new(x, y)]);
+            }
+        }
+    }
+}
+
+impl<T> RectGrid<T> {
+    // Every block with the number of positions it covers
+    pub fn blocks(&self) -> impl Iterator<Item = (Rect, &T)> + '_ {
+        self.cells.iter_positions().map(|(p, v)| {
+            let (x, y) = (p.x as usize, p.y as usize);
+            let min = I64Vec2::new(self.xs[x], self.ys[y]);
+            let max = I64Vec2::new(self.xs[x + 1], self.ys[y + 1]) - 1;
+
+            (Rect { min, max }, v)
+        })
+    }
+
+    // The sum of `f` over every position, including the ones outside of all the rectangles once
+    // `f` of the default value is 0
+    pub fn sum_by(&self, mut f: impl FnMut(&T) -> u64) -> u64 {
+        self.blocks().map(|(rect, v)| rect.area() * f(v)).sum()
+    }
+}
+
+#[cfg(test)]
+fn rect(min: (i64, i64), max: (i64, i64)) -> Rect {
+    Rect::new(min.into(), max.into())
+}
+
+#[test]
+fn rectangles_apply_to_their_blocks() {
+    let rects = [
+        rect((0, 0), (999, 999)),
+        rect((0, 0), (999, 0)),
+        rect((499, 499), (500, 500)),
+    ];
+    let mut lights = RectGrid::<bool>::new(rects);
+    let mut brightness = RectGrid::<u64>::new(rects);
+
+    lights.apply(rects[0], |v| *v = true);
+    lights.apply(rects[1], |v| *v = !*v);
+    lights.apply(rects[2], |v| *v = false);
+    brightness.apply(rects[0], |v| *v += 1);
+    brightness.apply(rects[1], |v| *v += 2);
+    brightness.apply(rects[2], |v| *v = v.saturating_sub(5));
+
+    assert_eq!(lights.sum_by(|&on| on as u64), 1_000_000 - 1000 - 4);
+    assert_eq!(brightness.sum_by(|&b| b), 1_000_000 + 2000 - 4);
+    assert_eq!(lights.blocks().count(), 3 * 4);
+}
+
+#[test]
+fn coordinates_can_be_huge() {
+    let rects = [
+        rect((-1 << 30, 0), (1 << 30, 1 << 20)),
+        rect((5, 5), (5, 5)),
+    ];
+    let mut grid = RectGrid::<u64>::new(rects);
+
+    grid.apply(rects[0], |v| *v += 1);
+    grid.apply(rects[1], |v| *v += 1);
+
+    assert_eq!(grid.sum_by(|&v| v), rects[0].area() + 1);
+}
+
+#[test]
+fn unknown_rectangles_cut_the_grid() {
+    let mut grid = RectGrid::<u64>::new([rect((0, 0), (9, 9))]);
+
+    grid.apply(rect((0, 0), (9, 9)), |v| *v += 1);
+    grid.apply(rect((5, -5), (14, 4)), |v| *v += 10);
+    grid.apply(rect((20, 20), (20, 20)), |v| *v += 100);
+
+    assert_eq!(grid.sum_by(|&v| v), 100 + 100 * 10 + 100);
+    assert_eq!(grid.sum_by(|&v| (v == 11) as u64), 25);
+}
@@ -0,0 +1,363 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap},
+    hash::Hash,
+    ops::Add,
+};
+
+use rustc_hash::FxHashMap;
+
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+#[derive(Clone, Debug)]
+pub struct Found<S, C> {
+    pub cost: C,
+    // From the start to the first goal reached, both included
+    pub path: Vec<S>,
+    // Only kept by the `_all` variants
+    pub predecessors: Option<Predecessors<S>>,
+}
+
+// Every way of reaching each visited state at its lowest cost
+#[derive(Clone, Debug)]
+pub struct Predecessors<S> {
+    states: Vec<S>,
+    index: FxHashMap<S, usize>,
+    preds: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<S: Hash + Eq> Predecessors<S> {
+    pub fn of<'a>(&'a self, state: &S) -> impl Iterator<Item = &'a S> + 'a {
+        self.index
+            .get(state)
+            .into_iter()
+            .flat_map(|&i| &self.preds[i])
+            .map(|&p| &self.states[p])
+    }
+
+    // Goal states reached at the optimal cost
+    pub fn goals(&self) -> impl Iterator<Item = &S> + '_ {
+        self.goals.iter().map(|&i| &self.states[i])
+    }
+
+    // Every state that lies on at least one optimal path to a goal, each yielded once
+    pub fn on_optimal_paths(&self) -> impl Iterator<Item = &S> + '_ {
+        let mut seen = vec![false; self.states.len()];
+        let mut stack = self.goals.clone();
+
+        for &goal in &self.goals {
+            seen[goal] = true;
+        }
+
+        std::iter::from_fn(move || {
+            let i = stack.pop()?;
+
+            for &p in &self.preds[i] {
+                if !std::mem::replace(&mut seen[p], true) {
+                    stack.push(p);
+                }
+            }
+
+            Some(&self.states[i])
+        })
+    }
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+    preds: Vec<usize>,
+    closed: bool,
+}
+
+fn run<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut index = FxHashMap::default();
+    let mut nodes = vec![];
+    let mut queue = BinaryHeap::new();
+
+    index.insert(start.clone(), 0);
+    queue.push(Reverse((heuristic(&start), C::default(), 0)));
+    nodes.push(Node {
+        state: start,
+        cost: C::default(),
+        parent: None,
+        preds: vec![],
+        closed: false,
+    });
+
+    let mut best: Option<C> = None;
+    let mut goals = vec![];
+
+    while let Some(Reverse((estimate, cost, i))) = queue.pop() {
+        // Ties with the best cost may still add predecessors, anything above can't
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+
+        if nodes[i].closed || cost > nodes[i].cost {
+            continue;
+        }
+
+        nodes[i].closed = true;
+
+        if is_goal(&nodes[i].state) {
+            best = Some(cost);
+            goals.push(i);
+
+            if all {
+                continue;
+            }
+
+            break;
+        }
+
+        for (next, step) in neighbours(&nodes[i].state) {
+            let next_cost = cost + step;
+
+            let j = match index.entry(next) {
+                Entry::Vacant(entry) => {
+                    let j = nodes.len();
+
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        cost: next_cost,
+                        parent: Some(i),
+                        preds: vec![i],
+                        closed: false,
+                    });
+                    entry.insert(j);
+
+                    j
+                }
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+
+                    // An inconsistent heuristic can close a state too early, so it's expanded again
+                    if next_cost < node.cost {
+                        node.cost = next_cost;
+                        node.parent = Some(i);
+                        node.preds = vec![i];
+                        node.closed = false;
+                    } else {
+                        if all && next_cost == node.cost && !node.preds.contains(&i) {
+                            node.preds.push(i);
+                        }
+
+                        continue;
+                    }
+
+                    *entry.get()
+                }
+            };
+
+            queue.push(Reverse((
+                next_cost + heuristic(&nodes[j].state),
+                next_cost,
+                j,
+            )));
+        }
+    }
+
+    let cost = best?;
+    let mut path = vec![];
+    let mut cursor = Some(goals[0]);
+
+    while let Some(i) = cursor {
+        path.push(nodes[i].state.clone());
+        cursor = nodes[i].parent;
+    }
+
+    path.reverse();
+
+    let predecessors = all.then(|| {
+        let (states, preds) = nodes.into_iter().map(|n| (n.state, n.preds)).unzip();
+
+        Predecessors {
+            states,
+            index,
+            preds,
+            goals,
+        }
+    });
+
+    Some(Found {
+        cost,
+        path,
+        predecessors,
+    })
+}
+
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    run(start, neighbours, |_| C::default(), is_goal, false)
+}
+
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    run(start, neighbours, |_| C::default(), is_goal, true)
+}
+
+// The heuristic must never overestimate the remaining cost. An inconsistent one still finds the
+// cheapest path, but may expand some states more than once.
+pub fn a_star<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    run(start, neighbours, heuristic, is_goal, false)
+}
+
+// Finding every optimal predecessor also needs a consistent heuristic
+pub fn a_star_all<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    run(start, neighbours, heuristic, is_goal, true)
+}
+
+#[cfg(test)]
+fn diamond(node: &char) -> Vec<(char, u32)> {
+    // a -> b -> d and a -> c -> d both cost 3, a -> d directly costs 5
+    match node {
+        'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+        'b' => vec![('d', 2)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_finds_the_cheapest_path() {
+    let found = dijkstra('a', diamond, |&n| n == 'd').unwrap();
+
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path.first(), Some(&'a'));
+    assert_eq!(found.path.last(), Some(&'d'));
+    assert_eq!(found.path.len(), 3);
+    assert!(found.predecessors.is_none());
+
+    assert!(dijkstra('b', diamond, |&n| n == 'a').is_none());
+}
+
+#[test]
+fn all_optimal_predecessors_are_kept() {
+    let found = dijkstra_all('a', diamond, |&n| n == 'd').unwrap();
+    let predecessors = found.predecessors.unwrap();
+
+    let mut preds = predecessors.of(&'d').copied().collect::<Vec<_>>();
+    preds.sort();
+
+    assert_eq!(found.cost, 3);
+    assert_eq!(preds, ['b', 'c']);
+    assert_eq!(predecessors.goals().collect::<Vec<_>>(), [&'d']);
+}
+
+#[test]
+fn optimal_paths_cover_every_tie() {
+    // Both b and c lead to the two goals d and e at the same cost, f is a dead end
+    let graph = |node: &char| match node {
+        'a' => vec![('b', 1), ('c', 1), ('f', 1)],
+        'b' | 'c' => vec![('d', 1), ('e', 1)],
+        'f' => vec![('d', 5)],
+        _ => vec![],
+    };
+
+    let found = dijkstra_all('a', graph, |&n| n == 'd' || n == 'e').unwrap();
+    let mut states = found
+        .predecessors
+        .unwrap()
+        .on_optimal_paths()
+        .copied()
+        .collect::<Vec<_>>();
+    states.sort();
+
+    assert_eq!(found.cost, 2);
+    assert_eq!(states, ['a', 'b', 'c', 'd', 'e']);
+}
+
+#[test]
+fn a_star_matches_dijkstra_on_a_grid() {
+    use glam::IVec2;
+
+    let walls = |p: IVec2| p.x == 2 && p.y < 4;
+    let neighbours = |p: &IVec2| {
+        [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+            .map(|d| *p + d)
+            .into_iter()
+            .filter(|n| n.cmpge(IVec2::ZERO).all() && n.cmplt(IVec2::splat(6)).all() && !walls(*n))
+            .map(|n| (n, 1))
+    };
+    let goal = IVec2::new(5, 0);
+
+    let a_star = a_star(
+        IVec2::ZERO,
+        neighbours,
+        |p| crate::manhattan(*p, goal),
+        |p| *p == goal,
+    );
+    let dijkstra = dijkstra(IVec2::ZERO, neighbours, |p| *p == goal);
+
+    assert_eq!(a_star.unwrap().cost, 13);
+    assert_eq!(dijkstra.unwrap().cost, 13);
+}
+
+#[test]
+fn closed_states_reopen_with_an_inconsistent_heuristic() {
+    // s -> b -> a -> g costs 5, but the heuristic at b is high enough to close a at cost 3 first
+    let graph = |node: &char| match node {
+        's' => vec![('a', 3), ('b', 1)],
+        'b' => vec![('a', 1)],
+        'a' => vec![('g', 3)],
+        _ => vec![],
+    };
+    let heuristic = |node: &char| if *node == 'b' { 4 } else { 0 };
+
+    let found = a_star('s', graph, heuristic, |&n| n == 'g').unwrap();
+
+    assert_eq!(found.cost, 5);
+    assert_eq!(found.path, ['s', 'b', 'a', 'g']);
+}
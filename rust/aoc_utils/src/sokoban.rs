@@ -0,0 +1,239 @@
+use std::collections::VecDeque;
+
+use anyhow::{Context, Result};
+use glam::IVec2;
+
+use crate::{Dir4, Map2D, Render};
+
+// A rectangular crate, `pos` being its top left cell
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Crate {
+    pub pos: IVec2,
+    pub size: IVec2,
+}
+
+impl Crate {
+    pub fn contains(&self, pos: IVec2) -> bool {
+        let pos = pos - self.pos;
+
+        pos.cmpge(IVec2::ZERO).all() && pos.cmplt(self.size).all()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = IVec2> {
+        let Crate { pos, size } = *self;
+
+        (0..size.y).flat_map(move |y| (0..size.x).map(move |x| pos + IVec2::new(x, y)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub dir: Dir4,
+    pub moved: bool,
+    // Indices of the crates that were pushed along
+    pub pushed: Vec<usize>,
+}
+
+// A robot pushing crates around walls. A push moves every crate in the way at once, and is blocked
+// as soon as any of them would run into a wall or leave the map.
+#[derive(Clone, Debug)]
+pub struct Sokoban {
+    walls: Map2D<bool>,
+    crates: Vec<Crate>,
+    // The crate covering every cell
+    owners: Map2D<Option<usize>>,
+    player: IVec2,
+    history: Vec<Step>,
+}
+
+pub fn parse_moves(moves: &str) -> Result<Vec<Dir4>> {
+    moves
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| Dir4::from_arrow(c).context("unexpected move"))
+        .collect()
+}
+
+impl Sokoban {
+    pub fn new(walls: Map2D<bool>, crates: impl IntoIterator<Item = Crate>, player: IVec2) -> Self {
+        let crates = crates.into_iter().collect::<Vec<_>>();
+        let mut owners = walls.map(|_| None);
+
+        for (i, c) in crates.iter().enumerate() {
+            for cell in c.cells() {
+                owners.set(cell, Some(i));
+            }
+        }
+
+        Self {
+            walls,
+            crates,
+            owners,
+            player,
+            history: vec![],
+        }
+    }
+
+    pub fn player(&self) -> IVec2 {
+        self.player
+    }
+
+    pub fn crates(&self) -> &[Crate] {
+        &self.crates
+    }
+
+    pub fn walls(&self) -> &Map2D<bool> {
+        &self.walls
+    }
+
+    pub fn push(&mut self, dir: Dir4) -> Step {
+        let d = dir.vec();
+        let mut pushed = vec![];
+        let mut queue = VecDeque::from([self.player + d]);
+
+        while let Some(pos) = queue.pop_front() {
+            if self.walls.get(pos) != Some(&false) {
+                return Step {
+                    dir,
+                    moved: false,
+                    pushed: vec![],
+                };
+            }
+
+            if let Some(&Some(i)) = self.owners.get(pos) {
+                if !pushed.contains(&i) {
+                    pushed.push(i);
+
+                    // Only the cells moving out of the crate's own footprint can hit something
+                    let c = self.crates[i];
+                    queue.extend(c.cells().map(|cell| cell + d).filter(|&p| !c.contains(p)));
+                }
+            }
+        }
+
+        let step = Step {
+            dir,
+            moved: true,
+            pushed,
+        };
+        self.shift(&step, d);
+        self.history.push(step.clone());
+
+        step
+    }
+
+    fn shift(&mut self, step: &Step, d: IVec2) {
+        for &i in &step.pushed {
+            for cell in self.crates[i].cells() {
+                self.owners.set(cell, None);
+            }
+        }
+
+        for &i in &step.pushed {
+            self.crates[i].pos += d;
+
+            for cell in self.crates[i].cells() {
+                self.owners.set(cell, Some(i));
+            }
+        }
+
+        self.player += d;
+    }
+
+    // Takes back the last move that went anywhere
+    pub fn undo(&mut self) -> Option<Step> {
+        let step = self.history.pop()?;
+        self.shift(&step, -step.dir.vec());
+
+        Some(step)
+    }
+
+    // Every step taken, blocked ones included
+    pub fn run(&mut self, moves: &[Dir4]) -> Vec<Step> {
+        moves.iter().map(|&dir| self.push(dir)).collect()
+    }
+
+    // Same as `run` from a string of arrows, leaving the warehouse untouched if it doesn't parse
+    pub fn replay(&mut self, moves: &str) -> Result<Vec<Step>> {
+        Ok(self.run(&parse_moves(moves)?))
+    }
+
+    // Crates one cell wide are drawn as `O`, wider ones as `[==]` on every row
+    pub fn render(&self) -> Render {
+        let crates = self.crates.iter().flat_map(|c| {
+            c.cells().map(move |cell| {
+                let x = (cell - c.pos).x;
+
+                let glyph = match (c.size.x, x) {
+                    (1, _) => 'O',
+                    (_, 0) => '[',
+                    (w, x) if x == w - 1 => ']',
+                    _ => '=',
+                };
+
+                (cell, glyph)
+            })
+        });
+
+        self.walls
+            .render(|&wall| if wall { '#' } else { '.' })
+            .overlay(crates, None)
+            .overlay([(self.player, '@')], None)
+    }
+}
+
+#[test]
+fn wide_crates_push_each_other() {
+    let walls = Map2D::read_str(
+        "#######\n#...#.#\n#.....#\n#.....#\n#.....#\n#.....#\n#######\n",
+        |c: char| c == '#',
+    )
+    .unwrap();
+    let crates = [(6, 3), (8, 3), (6, 4)].map(|pos| Crate {
+        pos: pos.into(),
+        size: IVec2::new(2, 1),
+    });
+    let mut sokoban = Sokoban::new(walls.scale(2, 1), crates, IVec2::new(10, 3));
+    let trace = sokoban.replay("<vv<<^^<<^^").unwrap();
+
+    assert_eq!(
+        sokoban.render().to_string(),
+        "##############\n\
+         ##...[].##..##\n\
+         ##...@.[]...##\n\
+         ##....[]....##\n\
+         ##..........##\n\
+         ##..........##\n\
+         ##############"
+    );
+    assert_eq!(trace[0].pushed.len(), 2);
+    assert_eq!(trace.len(), 11);
+    assert!(sokoban.replay("<x").is_err());
+    assert!(sokoban.replay("<L").is_err());
+}
+
+#[test]
+fn moves_can_be_undone() {
+    let walls = Map2D::read_str("#####\n#...#\n#####\n", |c: char| c == '#').unwrap();
+    let crates = [Crate {
+        pos: IVec2::new(6, 1),
+        size: IVec2::new(3, 1),
+    }];
+    let mut sokoban = Sokoban::new(walls.scale(3, 1), crates, IVec2::new(3, 1));
+    let start = sokoban.render().to_string();
+
+    assert_eq!(start, "###############\n###@..[=]...###\n###############");
+
+    let trace = sokoban.replay(">>>>>>>").unwrap();
+    let moved = trace.iter().filter(|s| s.moved).count();
+
+    assert_eq!(moved, 5);
+    assert_eq!(sokoban.crates()[0].pos, IVec2::new(9, 1));
+
+    for _ in 0..moved {
+        assert!(sokoban.undo().is_some());
+    }
+
+    assert!(sokoban.undo().is_none());
+    assert_eq!(sokoban.render().to_string(), start);
+}
@@ -0,0 +1,175 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::Params;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => anyhow::bail!("there is no part {value}"),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<impl Display>;
+    fn part2(input: &Self::Input) -> Result<impl Display>;
+
+    // Examples can be scaled down puzzles, set up by the `[params]` of their sidecar
+    fn parse_example(input: &str, params: &Params) -> Result<Self::Input> {
+        anyhow::ensure!(
+            params.is_empty(),
+            "day {} takes no example params",
+            Self::DAY
+        );
+
+        Self::parse(input)
+    }
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+}
+
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.parts.iter().all(|p| p.answer.is_ok())
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "parse: {:.2?}", self.parse_elapsed)?;
+
+        for PartReport {
+            part,
+            answer,
+            elapsed,
+        } in &self.parts
+        {
+            match answer {
+                Ok(answer) => writeln!(f, "part {part}: {answer} ({elapsed:.2?})")?,
+                Err(e) => writeln!(f, "part {part}: error: {e:#} ({elapsed:.2?})")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Report> {
+    solve_with::<S>(|| S::parse(input), parts)
+}
+
+pub fn solve_example<S: Solution>(input: &str, params: &Params, parts: &[Part]) -> Result<Report> {
+    solve_with::<S>(|| S::parse_example(input, params), parts)
+}
+
+fn solve_with<S: Solution>(
+    parse: impl FnOnce() -> Result<S::Input>,
+    parts: &[Part],
+) -> Result<Report> {
+    let start = Instant::now();
+    let input = parse()?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).map(|v| v.to_string()),
+                Part::Two => S::part2(&input).map(|v| v.to_string()),
+            };
+
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        parse_elapsed,
+        parts,
+    })
+}
+
+pub fn run<S: Solution>() -> Result<()> {
+    let arg = std::env::args().nth(1);
+    let input = crate::input::read(S::YEAR, S::DAY, arg.as_deref())?;
+    let report = solve::<S>(&input, &Part::ALL)?;
+
+    print!("{report}");
+
+    anyhow::ensure!(report.is_ok(), "some parts have failed");
+
+    Ok(())
+}
+
+// Object-safe counterpart of `Solution`, so that the runner can keep all days in one table
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Report>;
+    fn bench(&self, input: &str, runs: usize) -> Result<crate::DayBench>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Report> {
+        solve::<S>(input, parts)
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> Result<crate::DayBench> {
+        crate::bench::<S>(input, runs)
+    }
+}
@@ -0,0 +1,270 @@
+use std::ops::Index;
+
+use glam::IVec2;
+use rustc_hash::FxHashMap;
+
+use crate::{Dir4, Dir8, Map2D, Render};
+
+// An unbounded grid holding only the cells that were set, which keeps track of the smallest
+// box containing all of them
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<IVec2, T>,
+    // Both corners included
+    bounds: Option<(IVec2, IVec2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Keeps the cells of a dense map for which `keep` holds, at the same positions
+    pub fn from_map(map: &Map2D<T>, mut keep: impl FnMut(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        map.find(|_, v| keep(v))
+            .map(|(pos, v)| (pos, v.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<(IVec2, IVec2)> {
+        self.bounds
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn get_or_insert_default(&mut self, pos: IVec2) -> &mut T
+    where
+        T: Default,
+    {
+        self.grow(pos);
+        self.cells.entry(pos).or_default()
+    }
+
+    // Returns the previous value of the cell
+    pub fn insert(&mut self, pos: IVec2, v: T) -> Option<T> {
+        self.grow(pos);
+        self.cells.insert(pos, v)
+    }
+
+    pub fn remove(&mut self, pos: IVec2) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+
+        // Only cells on the edge of the box can make it shrink
+        if self
+            .bounds
+            .is_some_and(|(min, max)| pos.cmpeq(min).any() || pos.cmpeq(max).any())
+        {
+            self.bounds = None;
+
+            for pos in self.cells.keys().copied().collect::<Vec<_>>() {
+                self.grow(pos);
+            }
+        }
+
+        Some(removed)
+    }
+
+    fn grow(&mut self, pos: IVec2) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(pos), max.max(pos)),
+            None => (pos, pos),
+        });
+    }
+
+    // In no particular order
+    pub fn iter_positions(&self) -> impl Iterator<Item = (IVec2, &T)> + '_ {
+        self.cells.iter().map(|(&pos, v)| (pos, v))
+    }
+
+    pub fn find<'a>(
+        &'a self,
+        mut f: impl (FnMut(IVec2, &T) -> bool) + 'a,
+    ) -> impl Iterator<Item = (IVec2, &'a T)> + 'a {
+        self.iter_positions().filter(move |&(pos, v)| f(pos, v))
+    }
+
+    // The set orthogonal neighbours of `pos`, clockwise from the right
+    pub fn neighbours4(&self, pos: IVec2) -> impl Iterator<Item = (Dir4, IVec2, &T)> + '_ {
+        Dir4::ALL.into_iter().filter_map(move |d| {
+            let next = pos + d.vec();
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+
+    // Same as `neighbours4`, diagonals included
+    pub fn neighbours8(&self, pos: IVec2) -> impl Iterator<Item = (Dir8, IVec2, &T)> + '_ {
+        Dir8::ALL.into_iter().filter_map(move |d| {
+            let next = pos + d.vec();
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+
+    // The `width` by `height` block starting at `origin`, with unset cells left at their default
+    pub fn to_map(&self, origin: IVec2, width: usize, height: usize) -> Map2D<T>
+    where
+        T: Clone + Default,
+    {
+        Map2D::from_fn(width, height, |p| {
+            self.get(origin + p).cloned().unwrap_or_default()
+        })
+    }
+
+    // Every row of the bounding box from the top, unset cells being `None`
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>> + '_> + '_ {
+        // An inverted box for an empty grid, so that both ranges are empty
+        let (min, max) = self.bounds.unwrap_or((IVec2::ONE, IVec2::ZERO));
+
+        (min.y..=max.y).map(move |y| (min.x..=max.x).map(move |x| self.get(IVec2::new(x, y))))
+    }
+
+    // Every column of the bounding box from the left, unset cells being `None`
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>> + '_> + '_ {
+        let (min, max) = self.bounds.unwrap_or((IVec2::ONE, IVec2::ZERO));
+
+        (min.x..=max.x).map(move |x| (min.y..=max.y).map(move |y| self.get(IVec2::new(x, y))))
+    }
+
+    // Draws the bounding box, overlays use the grid's own coordinates
+    pub fn render(&self, glyph: impl Fn(Option<&T>) -> char) -> Render {
+        let Some((min, max)) = self.bounds else {
+            return Render::new(Map2D::new(0, 0), IVec2::ZERO);
+        };
+        let size = max - min + 1;
+        let glyphs = Map2D::from_fn(size.x as usize, size.y as usize, |p| {
+            glyph(self.get(min + p))
+        });
+
+        Render::new(glyphs, min)
+    }
+}
+
+impl<T> Index<IVec2> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("no cell at {pos} in the sparse grid"))
+    }
+}
+
+impl<T> FromIterator<(IVec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IVec2, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+
+        grid
+    }
+}
+
+impl<T> Extend<(IVec2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (IVec2, T)>>(&mut self, iter: I) {
+        for (pos, v) in iter {
+            self.insert(pos, v);
+        }
+    }
+}
+
+#[test]
+fn bounds_follow_the_cells() {
+    let mut grid = SparseGrid::new();
+
+    assert_eq!(grid.bounds(), None);
+
+    grid.insert(IVec2::new(-3, 2), 'a');
+    grid.insert(IVec2::new(4, -1), 'b');
+    grid.insert(IVec2::new(0, 0), 'c');
+    assert_eq!(grid.bounds(), Some((IVec2::new(-3, -1), IVec2::new(4, 2))));
+
+    grid.remove(IVec2::new(-3, 2));
+    assert_eq!(grid.bounds(), Some((IVec2::new(0, -1), IVec2::new(4, 0))));
+
+    *grid.get_or_insert_default(IVec2::new(1, 1)) = 'd';
+    assert_eq!(grid.len(), 3);
+    assert_eq!(
+        grid.neighbours4(IVec2::new(1, 0))
+            .map(|(_, _, &c)| c)
+            .collect::<String>(),
+        "dc"
+    );
+}
+
+#[test]
+fn sparse_grids_convert_to_and_from_dense_maps() {
+    let map = Map2D::read_str("#..\n..#\n", |c: char| c == '#').unwrap();
+    let grid = SparseGrid::from_map(&map, |&wall| wall);
+
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.to_map(IVec2::ZERO, 3, 2), map);
+    assert_eq!(
+        grid.render(|v| if v.is_some() { '#' } else { '.' })
+            .overlay([(IVec2::new(1, 0), '@')], None)
+            .to_string(),
+        "#@.\n..#"
+    );
+}
+
+#[test]
+fn sparse_grids_read_like_dense_maps() {
+    let mut grid = SparseGrid::from_iter([
+        (IVec2::new(2, -1), 'a'),
+        (IVec2::new(3, -1), 'c'),
+        (IVec2::new(3, 0), 'b'),
+    ]);
+
+    assert_eq!(grid[IVec2::new(3, 0)], 'b');
+
+    let rows = grid
+        .rows()
+        .map(|row| row.map(|v| v.copied().unwrap_or('.')).collect::<String>())
+        .collect::<Vec<_>>();
+    let columns = grid
+        .columns()
+        .map(|column| {
+            column
+                .map(|v| v.copied().unwrap_or('.'))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(rows, ["ac", ".b"]);
+    assert_eq!(columns, ["a.", "cb"]);
+
+    for pos in [IVec2::new(2, -1), IVec2::new(3, -1), IVec2::new(3, 0)] {
+        grid.remove(pos);
+    }
+
+    assert_eq!(grid.rows().count(), 0);
+    assert_eq!(grid.render(|_| '#').to_string(), "");
+}
@@ -0,0 +1,174 @@
+use glam::IVec2;
+
+use crate::{Dir4, Dir8, Map2D};
+
+// Coordinates within `0..extents` that wrap around on the enabled axes, so that a single axis
+// gives a cylinder and both a torus
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Wrapping {
+    pub extents: IVec2,
+    pub x: bool,
+    pub y: bool,
+}
+
+impl Wrapping {
+    pub fn new(extents: IVec2) -> Self {
+        assert!(
+            extents.cmpgt(IVec2::ZERO).all(),
+            "wrapping extents must be positive, got {extents}"
+        );
+
+        Self {
+            extents,
+            x: true,
+            y: true,
+        }
+    }
+
+    // Positions along an axis without wrapping are left as they are
+    pub fn wrap(&self, pos: IVec2) -> IVec2 {
+        IVec2::new(
+            if self.x {
+                pos.x.rem_euclid(self.extents.x)
+            } else {
+                pos.x
+            },
+            if self.y {
+                pos.y.rem_euclid(self.extents.y)
+            } else {
+                pos.y
+            },
+        )
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(IVec2::ZERO).all() && pos.cmplt(self.extents).all()
+    }
+
+    pub fn neighbours4(&self, pos: IVec2) -> impl Iterator<Item = (Dir4, IVec2)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .map(move |d| (d, self.wrap(pos + d.vec())))
+            .filter(|&(_, next)| self.contains(next))
+    }
+
+    pub fn neighbours8(&self, pos: IVec2) -> impl Iterator<Item = (Dir8, IVec2)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .map(move |d| (d, self.wrap(pos + d.vec())))
+            .filter(|&(_, next)| self.contains(next))
+    }
+
+    // 0 is the top left quadrant, then bottom left, top right and bottom right. With an odd
+    // extent the middle row or column belongs to none of them.
+    pub fn quadrant(&self, pos: IVec2) -> Option<usize> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        let half = |v: i32, extent: i32| match v {
+            v if v < extent / 2 => Some(0),
+            v if v >= (extent + 1) / 2 => Some(1),
+            _ => None,
+        };
+
+        Some(half(pos.x, self.extents.x)? * 2 + half(pos.y, self.extents.y)?)
+    }
+}
+
+impl<T> Map2D<T> {
+    // The map as a torus, turn off an axis for a cylinder
+    pub fn wrapping(&self) -> Wrapping {
+        Wrapping::new(IVec2::new(self.width() as i32, self.height() as i32))
+    }
+
+    pub fn get_wrapped(&self, wrapping: &Wrapping, pos: IVec2) -> Option<&T> {
+        self.get(wrapping.wrap(pos))
+    }
+
+    pub fn neighbours4_wrapped(
+        &self,
+        wrapping: &Wrapping,
+        pos: IVec2,
+    ) -> impl Iterator<Item = (Dir4, IVec2, &T)> + '_ {
+        let wrapping = *wrapping;
+
+        Dir4::ALL.into_iter().filter_map(move |d| {
+            let next = wrapping.wrap(pos + d.vec());
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+
+    pub fn neighbours8_wrapped(
+        &self,
+        wrapping: &Wrapping,
+        pos: IVec2,
+    ) -> impl Iterator<Item = (Dir8, IVec2, &T)> + '_ {
+        let wrapping = *wrapping;
+
+        Dir8::ALL.into_iter().filter_map(move |d| {
+            let next = wrapping.wrap(pos + d.vec());
+
+            self.get(next).map(|v| (d, next, v))
+        })
+    }
+}
+
+#[test]
+fn positions_wrap_on_enabled_axes() {
+    let torus = Wrapping::new(IVec2::new(11, 7));
+    let cylinder = Wrapping { y: false, ..torus };
+
+    assert_eq!(torus.wrap(IVec2::new(-1, 15)), IVec2::new(10, 1));
+    assert_eq!(cylinder.wrap(IVec2::new(-1, 15)), IVec2::new(10, 15));
+    assert_eq!(cylinder.neighbours4(IVec2::ZERO).count(), 3);
+    assert_eq!(torus.neighbours8(IVec2::ZERO).count(), 8);
+
+    let map = Map2D::read_str("ab\ncd\n", |c: char| c).unwrap();
+    let torus = map.wrapping();
+    let cylinder = Wrapping { y: false, ..torus };
+    let around = |space| {
+        map.neighbours4_wrapped(&space, IVec2::ZERO)
+            .map(|(_, _, &c)| c)
+            .collect::<String>()
+    };
+
+    assert_eq!(map.get_wrapped(&torus, IVec2::new(-1, -1)), Some(&'d'));
+    assert_eq!(map.get_wrapped(&cylinder, IVec2::new(-1, -1)), None);
+    assert_eq!(around(torus), "bcbc");
+    assert_eq!(around(cylinder), "bcb");
+    assert_eq!(map.neighbours8_wrapped(&cylinder, IVec2::ZERO).count(), 5);
+}
+
+#[test]
+#[should_panic(expected = "wrapping extents must be positive")]
+fn empty_extents_are_rejected() {
+    Wrapping::new(IVec2::new(0, 3));
+}
+
+#[test]
+fn quadrants_split_odd_and_even_extents() {
+    let quadrants = |extents: IVec2| {
+        let space = Wrapping::new(extents);
+
+        (0..extents.y)
+            .map(|y| {
+                (0..extents.x)
+                    .map(|x| match space.quadrant(IVec2::new(x, y)) {
+                        Some(q) => char::from(b'0' + q as u8),
+                        None => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(quadrants(IVec2::new(5, 3)), ["00.22", ".....", "11.33"]);
+    assert_eq!(quadrants(IVec2::new(4, 2)), ["0022", "1133"]);
+    assert_eq!(quadrants(IVec2::new(4, 3)), ["0022", "....", "1133"]);
+    assert_eq!(
+        Wrapping::new(IVec2::splat(4)).quadrant(IVec2::new(4, 0)),
+        None
+    );
+}